@@ -1,32 +1,39 @@
 #![no_std]
 
+use core::marker::PhantomData;
+
 use big_num::{
-    types::{U384, U640},
+    types::{U256, U384, U640},
     BigUint,
 };
-use elliptic_curve::{Curve, Numeric, Point};
-use modular::{Ring, Widened};
+use elliptic_curve::{Curve, FixedBaseTable, Numeric, Point};
+use modular::{
+    DivisionReducer, MontgomeryRing, P256Reducer, P384Reducer, P521Reducer, Reducer, Ring, Widened,
+};
 use numeric::Widen;
 
-pub struct Config<T> {
+// `R` picks the reduction used for arithmetic modulo p, the named curves use the fast reducers for
+// their special-form primes
+pub struct Config<T, R = DivisionReducer<T>> {
     pub p: T,
     pub a: T,
     pub b: T,
     pub g: Point<T>,
     pub n: T,
+    reducer: PhantomData<R>,
     _private: (),
 }
 
 pub struct InvalidGeneratorError;
 
-impl<T: Numeric> Config<T>
+impl<T: Numeric, R: Reducer<T>> Config<T, R>
 where
     <T as Widen>::Output: Widened<T>,
 {
     pub fn new(p: T, a: T, b: T, g: Point<T>, n: T) -> Result<Self, InvalidGeneratorError> {
-        let curve = Curve::new(a, b, p);
+        let curve = Curve::with_ring(a, b, Ring::<T, R>::with_reducer(p));
 
-        if !curve.is_valid_point(&g) {
+        if g.is_identity() || !curve.is_valid_point(&g) {
             return Err(InvalidGeneratorError);
         }
 
@@ -36,149 +43,173 @@ where
             b,
             g,
             n,
+            reducer: PhantomData,
             _private: (),
         })
     }
 
+    // n * G = O costs a full scalar multiplication, so `new` leaves it to the callers that build
+    // curves from untrusted parameters
+    pub fn generator_has_order_n(&self) -> bool {
+        self.get_curve().mul(&self.g, &self.n).is_identity()
+    }
+
     pub fn get_ring(&self) -> Ring<T> {
         Ring::new(self.n)
     }
 
-    pub fn get_curve(&self) -> Curve<T> {
-        Curve::new(self.a, self.b, self.p)
+    pub fn get_curve(&self) -> Curve<T, Ring<T, R>> {
+        Curve::with_ring(self.a, self.b, Ring::with_reducer(self.p))
+    }
+
+    pub fn generator_table(&self) -> FixedBaseTable<T> {
+        self.get_curve().precompute(&self.g, self.n.bit_length())
     }
 }
 
-pub const P256: Config<BigUint<8>> = Config {
-    p: BigUint::new([4294967295, 4294967295, 4294967295, 0, 0, 0, 1, 4294967295]),
-    a: BigUint::new([4294967292, 4294967295, 4294967295, 0, 0, 0, 1, 4294967295]),
-    b: BigUint::new([
-        668098635, 1003371582, 3428036854, 1696401072, 1989707452, 3018571093, 2855965671,
-        1522939352,
-    ]),
+impl<const N: usize, R> Config<BigUint<N>, R>
+where
+    BigUint<N>: Numeric,
+{
+    pub fn get_montgomery_curve(&self) -> Curve<BigUint<N>, MontgomeryRing<N>> {
+        Curve::with_ring(self.a, self.b, MontgomeryRing::new(self.p))
+    }
+}
+
+pub const P256: Config<U256, P256Reducer> = Config {
+    p: BigUint::from_be_hex_const(
+        "ffffffff 00000001 00000000 00000000 00000000 ffffffff \
+         ffffffff ffffffff",
+    ),
+    a: BigUint::from_be_hex_const(
+        "ffffffff 00000001 00000000 00000000 00000000 ffffffff \
+         ffffffff fffffffc",
+    ),
+    b: BigUint::from_be_hex_const(
+        "5ac635d8 aa3a93e7 b3ebbd55 769886bc 651d06b0 cc53b0f6 \
+         3bce3c3e 27d2604b",
+    ),
     g: Point::new(
-        BigUint::new([
-            3633889942, 4104206661, 770388896, 1996717441, 1671708914, 4173129445, 3777774151,
-            1796723186,
-        ]),
-        BigUint::new([
-            935285237, 3417718888, 1798397646, 734933847, 2081398294, 2397563722, 4263149467,
-            1340293858,
-        ]),
+        BigUint::from_be_hex_const(
+            "6b17d1f2 e12c4247 f8bce6e5 63a440f2 77037d81 2deb33a0 \
+             f4a13945 d898c296",
+        ),
+        BigUint::from_be_hex_const(
+            "4fe342e2 fe1a7f9b 8ee7eb4a 7c0f9e16 2bce3357 6b315ece \
+             cbb64068 37bf51f5",
+        ),
+    ),
+    n: BigUint::from_be_hex_const(
+        "ffffffff 00000000 ffffffff ffffffff bce6faad a7179e84 \
+         f3b9cac2 fc632551",
     ),
-    n: BigUint::new([
-        4234356049, 4089039554, 2803342980, 3169254061, 4294967295, 4294967295, 0, 4294967295,
-    ]),
+    reducer: PhantomData,
     _private: (),
 };
 
-pub const P384: Config<U384> = Config {
-    p: BigUint::new([
-        4294967295, 0, 0, 4294967295, 4294967294, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295,
-    ]),
-    a: BigUint::new([
-        4294967292, 0, 0, 4294967295, 4294967294, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295,
-    ]),
-    b: BigUint::new([
-        3555470063, 713410797, 2318324125, 3327539597, 1343457114, 51644559, 4269883666, 404593774,
-        3824692505, 2559444331, 3795773412, 3006345127,
-    ]),
+pub const P384: Config<U384, P384Reducer> = Config {
+    p: BigUint::from_be_hex_const(
+        "ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff fffffffe ffffffff 00000000 00000000 ffffffff",
+    ),
+    a: BigUint::from_be_hex_const(
+        "ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff fffffffe ffffffff 00000000 00000000 fffffffc",
+    ),
+    b: BigUint::from_be_hex_const(
+        "b3312fa7 e23ee7e4 988e056b e3f82d19 181d9c6e fe814112 \
+         0314088f 5013875a c656398d 8a2ed19d 2a85c8ed d3ec2aef",
+    ),
     g: Point::new(
-        BigUint::new([
-            1920338615, 978607672, 3210029420, 1426256477, 2186553912, 1509376480, 2343017368,
-            1847409506, 4079005044, 2394015518, 3196781879, 2861025826,
-        ]),
-        BigUint::new([
-            2431258207, 2051218812, 494829981, 174109134, 3052452032, 3923390739, 681186428,
-            4176747965, 2459098153, 1570674879, 2519084143, 907533898,
-        ]),
+        BigUint::from_be_hex_const(
+            "aa87ca22 be8b0537 8eb1c71e f320ad74 6e1d3b62 8ba79b98 \
+             59f741e0 82542a38 5502f25d bf55296c 3a545e38 72760ab7",
+        ),
+        BigUint::from_be_hex_const(
+            "3617de4a 96262c6f 5d9e98bf 9292dc29 f8f41dbd 289a147c \
+             e9da3113 b5f0b8c0 0a60b1ce 1d7e819d 7a431d7c 90ea0e5f",
+        ),
+    ),
+    n: BigUint::from_be_hex_const(
+        "ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         c7634d81 f4372ddf 581a0db2 48b0a77a ecec196a ccc52973",
     ),
-    n: BigUint::new([
-        3435473267, 3974895978, 1219536762, 1478102450, 4097256927, 3345173889, 4294967295,
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-    ]),
+    reducer: PhantomData,
     _private: (),
 };
 
-pub const P521: Config<U640> = Config {
-    p: BigUint::new([
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 511, 0, 0, 0,
-    ]),
-    a: BigUint::new([
-        4294967292, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 511, 0, 0, 0,
-    ]),
-    b: BigUint::new([
-        1800421120, 4014284756, 1026307313, 896786312, 1001504519, 374522045, 3967718267,
-        1444493649, 2398161377, 3098839441, 2578650611, 2732225115, 3062186222, 2459574688,
-        2384239135, 2503915873, 81, 0, 0, 0,
-    ]),
+pub const P521: Config<U640, P521Reducer> = Config {
+    p: BigUint::from_be_hex_const(
+        "000001ff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff ffffffff",
+    ),
+    a: BigUint::from_be_hex_const(
+        "000001ff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff fffffffc",
+    ),
+    b: BigUint::from_be_hex_const(
+        "00000051 953eb961 8e1c9a1f 929a21a0 b68540ee a2da725b \
+         99b315f3 b8b48991 8ef109e1 56193951 ec7e937b 1652c0bd \
+         3bb1bf07 3573df88 3d2c34f1 ef451fd4 6b503f00",
+    ),
     g: Point::new(
-        BigUint::new([
-            3269836134, 4185816625, 2238333595, 860402625, 2734663902, 4263362855, 4024916264,
-            2706071159, 1800224186, 4163415904, 88061217, 2623832377, 597013570, 2654915430,
-            67430861, 2240677559, 198, 0, 0, 0,
-        ]),
-        BigUint::new([
-            2681300560, 2294191222, 2725429824, 893153414, 1068304225, 3310401793, 1593058880,
-            2548986521, 658400812, 397393175, 1469793384, 2566210633, 746396633, 1552572340,
-            2587607044, 959015544, 280, 0, 0, 0,
-        ]),
+        BigUint::from_be_hex_const(
+            "000000c6 858e06b7 0404e9cd 9e3ecb66 2395b442 9c648139 \
+             053fb521 f828af60 6b4d3dba a14b5e77 efe75928 fe1dc127 \
+             a2ffa8de 3348b3c1 856a429b f97e7e31 c2e5bd66",
+        ),
+        BigUint::from_be_hex_const(
+            "00000118 39296a78 9a3bc004 5c8a5fb4 2c7d1bd9 98f54449 \
+             579b4468 17afbd17 273e662c 97ee7299 5ef42640 c550b901 \
+             3fad0761 353c7086 a272c240 88be9476 9fd16650",
+        ),
+    ),
+    n: BigUint::from_be_hex_const(
+        "000001ff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff fffffffa 51868783 bf2f966b 7fcc0148 \
+         f709a5d0 3bb5c9b8 899c47ae bb6fb71e 91386409",
     ),
-    n: BigUint::new([
-        2436391945, 3144660766, 2308720558, 1001769400, 4144604624, 2144076104, 3207566955,
-        1367771011, 4294967290, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 511, 0, 0, 0,
-    ]),
+    reducer: PhantomData,
     _private: (),
 };
 
 #[cfg(test)]
 mod tests {
-    extern crate alloc;
-
-    use alloc::{format, string::String};
     use big_num::BigUint;
     use numeric::FromStrRadix;
 
-    fn generate_config<const N: usize>(
-        p: &str,
-        a: &str,
-        b: &str,
-        g: (&str, &str),
-        n: &str,
-    ) -> String {
-        format!(
-            "Config {{ p: {}, a: {}, b: {}, g: Point::new({}, {}), n: {}, _private: ()}};",
-            generate_big_num_constructor::<N>(p),
-            generate_big_num_constructor::<N>(a),
-            generate_big_num_constructor::<N>(b),
-            generate_big_num_constructor::<N>(g.0),
-            generate_big_num_constructor::<N>(g.1),
-            generate_big_num_constructor::<N>(n)
-        )
-    }
+    use super::{Config, P256Reducer, P384Reducer, P521Reducer, P256, P384, P521};
 
-    fn generate_big_num_constructor<const N: usize>(s: &str) -> String {
-        let big_num: BigUint<N> = BigUint::from_str_radix(s, 16).unwrap();
-        format!("BigUint::new({:?})", big_num.to_limbs())
+    #[test]
+    fn montgomery_curve_matches_ring_curve() {
+        let k = "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721";
+
+        let curve = P256.get_curve();
+        let montgomery = P256.get_montgomery_curve();
+        let d = BigUint::from_str_radix(k, 16).unwrap();
+        assert_eq!(curve.mul(&P256.g, &d), montgomery.mul(&P256.g, &d));
+        assert_eq!(
+            curve.add(&P256.g, &P256.g),
+            montgomery.add(&P256.g, &P256.g)
+        );
+        assert!(montgomery.is_valid_point(&P256.g));
+
+        let curve = P384.get_curve();
+        let montgomery = P384.get_montgomery_curve();
+        let d = BigUint::from_str_radix(k, 16).unwrap();
+        assert_eq!(curve.mul(&P384.g, &d), montgomery.mul(&P384.g, &d));
     }
 
     #[test]
-    fn gen_p256() {
-        let cfg = generate_config::<20>(
-        "1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
-        "1fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc",
-        "51953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
-        ("c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66", "11839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650"),
-        "1fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
-    );
-
-        assert_eq!(cfg, "")
+    fn named_curves_are_valid() {
+        let p256 = Config::<_, P256Reducer>::new(P256.p, P256.a, P256.b, P256.g, P256.n);
+        let p384 = Config::<_, P384Reducer>::new(P384.p, P384.a, P384.b, P384.g, P384.n);
+        let p521 = Config::<_, P521Reducer>::new(P521.p, P521.a, P521.b, P521.g, P521.n);
+
+        assert!(p256.is_ok_and(|config| config.generator_has_order_n()));
+        assert!(p384.is_ok_and(|config| config.generator_has_order_n()));
+        assert!(p521.is_ok_and(|config| config.generator_has_order_n()));
     }
 }
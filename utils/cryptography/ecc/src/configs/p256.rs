@@ -1,27 +1,35 @@
 use super::Config;
+use big_num::types::U256;
 use big_num::BigUint;
 use elliptic_curve::Point;
 
-pub const P256: Config<BigUint<8>> = Config {
-    p: BigUint::new([4294967295, 4294967295, 4294967295, 0, 0, 0, 1, 4294967295]),
-    a: BigUint::new([4294967292, 4294967295, 4294967295, 0, 0, 0, 1, 4294967295]),
-    b: BigUint::new([
-        668098635, 1003371582, 3428036854, 1696401072, 1989707452, 3018571093, 2855965671,
-        1522939352,
-    ]),
+pub const P256: Config<U256> = Config {
+    p: BigUint::from_be_hex_const(
+        "ffffffff 00000001 00000000 00000000 00000000 ffffffff \
+         ffffffff ffffffff",
+    ),
+    a: BigUint::from_be_hex_const(
+        "ffffffff 00000001 00000000 00000000 00000000 ffffffff \
+         ffffffff fffffffc",
+    ),
+    b: BigUint::from_be_hex_const(
+        "5ac635d8 aa3a93e7 b3ebbd55 769886bc 651d06b0 cc53b0f6 \
+         3bce3c3e 27d2604b",
+    ),
     g: Point::new(
-        BigUint::new([
-            3633889942, 4104206661, 770388896, 1996717441, 1671708914, 4173129445, 3777774151,
-            1796723186,
-        ]),
-        BigUint::new([
-            935285237, 3417718888, 1798397646, 734933847, 2081398294, 2397563722, 4263149467,
-            1340293858,
-        ]),
+        BigUint::from_be_hex_const(
+            "6b17d1f2 e12c4247 f8bce6e5 63a440f2 77037d81 2deb33a0 \
+             f4a13945 d898c296",
+        ),
+        BigUint::from_be_hex_const(
+            "4fe342e2 fe1a7f9b 8ee7eb4a 7c0f9e16 2bce3357 6b315ece \
+             cbb64068 37bf51f5",
+        ),
+    ),
+    n: BigUint::from_be_hex_const(
+        "ffffffff 00000000 ffffffff ffffffff bce6faad a7179e84 \
+         f3b9cac2 fc632551",
     ),
-    n: BigUint::new([
-        4234356049, 4089039554, 2803342980, 3169254061, 4294967295, 4294967295, 0, 4294967295,
-    ]),
     _private: (),
 };
 
@@ -4,32 +4,32 @@ use big_num::BigUint;
 use elliptic_curve::Point;
 
 pub const P384: Config<U384> = Config {
-    p: BigUint::new([
-        4294967295, 0, 0, 4294967295, 4294967294, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295,
-    ]),
-    a: BigUint::new([
-        4294967292, 0, 0, 4294967295, 4294967294, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295,
-    ]),
-    b: BigUint::new([
-        3555470063, 713410797, 2318324125, 3327539597, 1343457114, 51644559, 4269883666, 404593774,
-        3824692505, 2559444331, 3795773412, 3006345127,
-    ]),
+    p: BigUint::from_be_hex_const(
+        "ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff fffffffe ffffffff 00000000 00000000 ffffffff",
+    ),
+    a: BigUint::from_be_hex_const(
+        "ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff fffffffe ffffffff 00000000 00000000 fffffffc",
+    ),
+    b: BigUint::from_be_hex_const(
+        "b3312fa7 e23ee7e4 988e056b e3f82d19 181d9c6e fe814112 \
+         0314088f 5013875a c656398d 8a2ed19d 2a85c8ed d3ec2aef",
+    ),
     g: Point::new(
-        BigUint::new([
-            1920338615, 978607672, 3210029420, 1426256477, 2186553912, 1509376480, 2343017368,
-            1847409506, 4079005044, 2394015518, 3196781879, 2861025826,
-        ]),
-        BigUint::new([
-            2431258207, 2051218812, 494829981, 174109134, 3052452032, 3923390739, 681186428,
-            4176747965, 2459098153, 1570674879, 2519084143, 907533898,
-        ]),
+        BigUint::from_be_hex_const(
+            "aa87ca22 be8b0537 8eb1c71e f320ad74 6e1d3b62 8ba79b98 \
+             59f741e0 82542a38 5502f25d bf55296c 3a545e38 72760ab7",
+        ),
+        BigUint::from_be_hex_const(
+            "3617de4a 96262c6f 5d9e98bf 9292dc29 f8f41dbd 289a147c \
+             e9da3113 b5f0b8c0 0a60b1ce 1d7e819d 7a431d7c 90ea0e5f",
+        ),
+    ),
+    n: BigUint::from_be_hex_const(
+        "ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         c7634d81 f4372ddf 581a0db2 48b0a77a ecec196a ccc52973",
     ),
-    n: BigUint::new([
-        3435473267, 3974895978, 1219536762, 1478102450, 4097256927, 3345173889, 4294967295,
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-    ]),
     _private: (),
 };
 
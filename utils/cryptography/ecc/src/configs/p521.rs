@@ -4,38 +4,38 @@ use big_num::BigUint;
 use elliptic_curve::Point;
 
 pub const P521: Config<U640> = Config {
-    p: BigUint::new([
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 511, 0, 0, 0,
-    ]),
-    a: BigUint::new([
-        4294967292, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 511, 0, 0, 0,
-    ]),
-    b: BigUint::new([
-        1800421120, 4014284756, 1026307313, 896786312, 1001504519, 374522045, 3967718267,
-        1444493649, 2398161377, 3098839441, 2578650611, 2732225115, 3062186222, 2459574688,
-        2384239135, 2503915873, 81, 0, 0, 0,
-    ]),
+    p: BigUint::from_be_hex_const(
+        "000001ff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff ffffffff",
+    ),
+    a: BigUint::from_be_hex_const(
+        "000001ff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff ffffffff ffffffff fffffffc",
+    ),
+    b: BigUint::from_be_hex_const(
+        "00000051 953eb961 8e1c9a1f 929a21a0 b68540ee a2da725b \
+         99b315f3 b8b48991 8ef109e1 56193951 ec7e937b 1652c0bd \
+         3bb1bf07 3573df88 3d2c34f1 ef451fd4 6b503f00",
+    ),
     g: Point::new(
-        BigUint::new([
-            3269836134, 4185816625, 2238333595, 860402625, 2734663902, 4263362855, 4024916264,
-            2706071159, 1800224186, 4163415904, 88061217, 2623832377, 597013570, 2654915430,
-            67430861, 2240677559, 198, 0, 0, 0,
-        ]),
-        BigUint::new([
-            2681300560, 2294191222, 2725429824, 893153414, 1068304225, 3310401793, 1593058880,
-            2548986521, 658400812, 397393175, 1469793384, 2566210633, 746396633, 1552572340,
-            2587607044, 959015544, 280, 0, 0, 0,
-        ]),
+        BigUint::from_be_hex_const(
+            "000000c6 858e06b7 0404e9cd 9e3ecb66 2395b442 9c648139 \
+             053fb521 f828af60 6b4d3dba a14b5e77 efe75928 fe1dc127 \
+             a2ffa8de 3348b3c1 856a429b f97e7e31 c2e5bd66",
+        ),
+        BigUint::from_be_hex_const(
+            "00000118 39296a78 9a3bc004 5c8a5fb4 2c7d1bd9 98f54449 \
+             579b4468 17afbd17 273e662c 97ee7299 5ef42640 c550b901 \
+             3fad0761 353c7086 a272c240 88be9476 9fd16650",
+        ),
+    ),
+    n: BigUint::from_be_hex_const(
+        "000001ff ffffffff ffffffff ffffffff ffffffff ffffffff \
+         ffffffff ffffffff fffffffa 51868783 bf2f966b 7fcc0148 \
+         f709a5d0 3bb5c9b8 899c47ae bb6fb71e 91386409",
     ),
-    n: BigUint::new([
-        2436391945, 3144660766, 2308720558, 1001769400, 4144604624, 2144076104, 3207566955,
-        1367771011, 4294967290, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
-        4294967295, 4294967295, 511, 0, 0, 0,
-    ]),
     _private: (),
 };
 
@@ -8,14 +8,15 @@ use alloc::{format, string::String};
 use core::fmt::LowerHex;
 
 use curves::Config;
-use elliptic_curve::Numeric;
-use modular::Widened;
+use elliptic_curve::{FixedBaseTable, Numeric, Point};
+use modular::{DivisionReducer, Reducer, Widened};
 use numeric::Widen;
 use steps::define_steps;
 
 use wasm_bindgen::prelude::*;
-pub struct Ecdh<T> {
-    config: Config<T>,
+pub struct Ecdh<T, R = DivisionReducer<T>> {
+    config: Config<T, R>,
+    generator_table: Option<FixedBaseTable<T>>,
 }
 
 define_steps! {
@@ -66,12 +67,31 @@ pub struct HexPair(pub String, pub String);
 #[derive(Clone, Default)]
 pub struct KeyPair(pub HexPair, pub HexPair);
 
-impl<T: Numeric + Default + LowerHex> Ecdh<T>
+impl<T: Numeric + Default + LowerHex, R: Reducer<T>> Ecdh<T, R>
 where
     <T as Widen>::Output: Widened<T>,
 {
-    pub fn new(config: Config<T>) -> Self {
-        Self { config }
+    pub fn new(config: Config<T, R>) -> Self {
+        Self {
+            config,
+            generator_table: None,
+        }
+    }
+
+    pub fn with_generator_table(config: Config<T, R>) -> Self {
+        let generator_table = Some(config.generator_table());
+
+        Self {
+            config,
+            generator_table,
+        }
+    }
+
+    fn generate_public_key(&self, private_key: &T) -> Point<T> {
+        match &self.generator_table {
+            Some(table) => self.config.get_curve().mul_fixed(table, private_key),
+            None => self.config.get_curve().mul(&self.config.g, private_key),
+        }
     }
 
     pub fn compute_shared_secret(&self, private_key_1: &T, private_key_2: &T) -> Option<Steps> {
@@ -84,30 +104,27 @@ where
             GeneratePrivateKeysChildren(),
         );
 
-        let g = &self.config.g;
         let curve = self.config.get_curve();
-        let public_key_1 = curve.mul(g, private_key_1)?;
-        let public_key_2 = curve.mul(g, private_key_2)?;
+        let public_key_1 = self.generate_public_key(private_key_1);
+        let public_key_2 = self.generate_public_key(private_key_2);
+        let (Point::Affine { x: x1, y: y1 }, Point::Affine { x: x2, y: y2 }) =
+            (&public_key_1, &public_key_2)
+        else {
+            return None;
+        };
         steps.generate_public_keys = GeneratePublicKeys::new(
             KeyPair(
-                HexPair(
-                    format!("{:x}", public_key_1.x),
-                    format!("{:x}", public_key_1.y),
-                ),
-                HexPair(
-                    format!("{:x}", public_key_2.x),
-                    format!("{:x}", public_key_2.y),
-                ),
+                HexPair(format!("{:x}", x1), format!("{:x}", y1)),
+                HexPair(format!("{:x}", x2), format!("{:x}", y2)),
             ),
             GeneratePublicKeysChildren(),
         );
 
-        let shared_point = curve.mul(&public_key_2, private_key_1)?;
+        let Point::Affine { x, y } = curve.mul(&public_key_2, private_key_1) else {
+            return None;
+        };
         steps.compute_shared_secret = ComputeSharedSecret::new(
-            HexPair(
-                format!("{:x}", shared_point.x),
-                format!("{:x}", shared_point.y),
-            ),
+            HexPair(format!("{:x}", x), format!("{:x}", y)),
             ComputeSharedSecretChildren(),
         );
 
@@ -1,5 +1,5 @@
 use alloc::string::String;
-use big_num::BigUint;
+use big_num::{BigUint, ParseBigIntError};
 use wasm_bindgen::prelude::*;
 extern crate console_error_panic_hook;
 use crate::{Ecdh, Steps};
@@ -12,38 +12,123 @@ pub enum CurveType {
     P521,
 }
 
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EcdhErrorKind {
+    EmptyNumber,
+    InvalidRadix,
+    InvalidDigit,
+    NumberTooLarge,
+    InvalidPrivateKey,
+}
+
+// wasm_bindgen enums cannot carry data, so the kind is paired with the position of the offending
+// character for the errors that have one
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EcdhError {
+    pub kind: EcdhErrorKind,
+    pub position: Option<usize>,
+}
+
+impl From<EcdhErrorKind> for EcdhError {
+    fn from(kind: EcdhErrorKind) -> Self {
+        EcdhError {
+            kind,
+            position: None,
+        }
+    }
+}
+
+impl From<ParseBigIntError> for EcdhError {
+    fn from(error: ParseBigIntError) -> Self {
+        let (kind, position) = match error {
+            ParseBigIntError::Empty => (EcdhErrorKind::EmptyNumber, None),
+            ParseBigIntError::InvalidRadix(_) => (EcdhErrorKind::InvalidRadix, None),
+            ParseBigIntError::InvalidChar { position } => {
+                (EcdhErrorKind::InvalidDigit, Some(position))
+            }
+            ParseBigIntError::Overflow { position } => {
+                (EcdhErrorKind::NumberTooLarge, Some(position))
+            }
+        };
+
+        EcdhError { kind, position }
+    }
+}
+
 impl CurveType {
-    fn compute_shared_secret(self, private_key_1: &str, private_key_2: &str) -> Option<Steps> {
-        match self {
+    fn compute_shared_secret(
+        self,
+        private_key_1: &str,
+        private_key_2: &str,
+    ) -> Result<Steps, EcdhError> {
+        let steps = match self {
             CurveType::P256 => {
                 let ecdh = Ecdh::new(curves::P256);
                 ecdh.compute_shared_secret(
-                    &BigUint::from_be_hex(&private_key_1).unwrap(),
-                    &BigUint::from_be_hex(&private_key_2).unwrap(),
+                    &BigUint::from_be_hex(private_key_1)?,
+                    &BigUint::from_be_hex(private_key_2)?,
                 )
             }
             CurveType::P384 => {
                 let ecdh = Ecdh::new(curves::P384);
                 ecdh.compute_shared_secret(
-                    &BigUint::from_be_hex(&private_key_1).unwrap(),
-                    &BigUint::from_be_hex(&private_key_2).unwrap(),
+                    &BigUint::from_be_hex(private_key_1)?,
+                    &BigUint::from_be_hex(private_key_2)?,
                 )
             }
             CurveType::P521 => {
                 let ecdh = Ecdh::new(curves::P521);
                 ecdh.compute_shared_secret(
-                    &BigUint::from_be_hex(&private_key_1).unwrap(),
-                    &BigUint::from_be_hex(&private_key_2).unwrap(),
+                    &BigUint::from_be_hex(private_key_1)?,
+                    &BigUint::from_be_hex(private_key_2)?,
                 )
             }
-        }
+        };
+
+        steps.ok_or(EcdhErrorKind::InvalidPrivateKey.into())
     }
 }
 
 #[wasm_bindgen]
-pub fn compute_shared_secret(private_key_1: &str, private_key_2: &str, curve: CurveType) -> Steps {
+pub fn compute_shared_secret(
+    private_key_1: &str,
+    private_key_2: &str,
+    curve: CurveType,
+) -> Result<Steps, EcdhError> {
     console_error_panic_hook::set_once();
-    curve
-        .compute_shared_secret(private_key_1, private_key_2)
-        .unwrap()
+    curve.compute_shared_secret(private_key_1, private_key_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(result: Result<Steps, EcdhError>) -> (EcdhErrorKind, Option<usize>) {
+        let error = result.err().unwrap();
+        (error.kind, error.position)
+    }
+
+    #[test]
+    fn invalid_private_keys() {
+        let too_large = "f".repeat(65);
+
+        assert_eq!(
+            (EcdhErrorKind::NumberTooLarge, Some(64)),
+            error(CurveType::P256.compute_shared_secret(&too_large, "1"))
+        );
+        assert_eq!(
+            (EcdhErrorKind::InvalidDigit, Some(2)),
+            error(CurveType::P384.compute_shared_secret("1", "12g4"))
+        );
+        assert_eq!(
+            (EcdhErrorKind::EmptyNumber, None),
+            error(CurveType::P521.compute_shared_secret("", "1"))
+        );
+        assert_eq!(
+            (EcdhErrorKind::InvalidPrivateKey, None),
+            error(CurveType::P256.compute_shared_secret("0", "1"))
+        );
+    }
 }
@@ -8,12 +8,13 @@ pub mod wasm_adapter;
 extern crate alloc;
 
 use curves::Config;
-use elliptic_curve::{Curve, Numeric, Point};
-use modular::{Ring, Widened};
+use elliptic_curve::{FixedBaseTable, Numeric, Point};
+use modular::{DivisionReducer, Reducer, Ring, Widened};
 use numeric::Widen;
 
-pub struct Ecdsa<T> {
-    config: Config<T>,
+pub struct Ecdsa<T, R = DivisionReducer<T>> {
+    config: Config<T, R>,
+    generator_table: Option<FixedBaseTable<T>>,
 }
 
 #[derive(Debug)]
@@ -44,12 +45,26 @@ impl<T> Default for VerifyingIntermediateValues<T> {
     }
 }
 
-impl<T: Numeric> Ecdsa<T>
+impl<T: Numeric, R: Reducer<T>> Ecdsa<T, R>
 where
     <T as Widen>::Output: Widened<T>,
 {
-    pub const fn new(config: Config<T>) -> Self {
-        Self { config }
+    pub const fn new(config: Config<T, R>) -> Self {
+        Self {
+            config,
+            generator_table: None,
+        }
+    }
+
+    // Precomputing multiples of the generator removes the doublings from signing. Building the table
+    // takes bit_length(n) / 4 windows of 2^4 point additions, once
+    pub fn with_generator_table(config: Config<T, R>) -> Self {
+        let generator_table = Some(config.generator_table());
+
+        Self {
+            config,
+            generator_table,
+        }
     }
 
     pub fn sign(
@@ -63,12 +78,15 @@ where
         let curve = self.config.get_curve();
         let ring = self.config.get_ring();
 
-        let point = match curve.mul(&g, k) {
-            Some(point) => point,
-            None => return Err(SigningError::InvalidPoint),
+        let point = match &self.generator_table {
+            Some(table) => curve.mul_fixed(table, k),
+            None => curve.mul(g, k),
         };
 
-        let r = point.x.rem_euclid(n);
+        let r = match &point {
+            Point::Affine { x, .. } => x.rem_euclid(n),
+            Point::Identity => return Err(SigningError::InvalidPoint),
+        };
 
         if r == T::zero() {
             return Err(SigningError::ZeroingK);
@@ -93,14 +111,18 @@ where
         hash: &T,
         signature: &(T, T),
     ) -> VerifyingIntermediateValues<T> {
-        let Config { p, a, b, g, n, .. } = &self.config;
+        let Config { g, n, .. } = &self.config;
         let mut intermediate_values = VerifyingIntermediateValues::default();
 
-        let curve = Curve::new(*a, *b, *p);
+        let curve = self.config.get_curve();
         let ring = Ring::new(*n);
 
         let (r, s) = *signature;
 
+        if key.is_identity() {
+            return intermediate_values;
+        }
+
         let s_inv = match ring.inv(s) {
             Some(inv) => inv,
             None => return intermediate_values,
@@ -110,29 +132,16 @@ where
         let u2 = ring.mul(r, s_inv);
         intermediate_values.u = Some((u1, u2));
 
-        let point1 = match curve.mul(g, &u1) {
-            Some(point) => point,
-            None => return intermediate_values,
-        };
+        let random_point = curve.mul_multi(&[(g, &u1), (key, &u2)]);
 
-        let point2 = match curve.mul(key, &u2) {
-            Some(point) => point,
-            None => return intermediate_values,
-        };
+        intermediate_values.generated_point = Some(random_point.clone());
 
-        let random_point = match curve.add(&point1, &point2) {
-            Some(point) => point,
-            None => return intermediate_values,
+        intermediate_values.valid = match random_point {
+            Point::Affine { x, .. } => r == x.rem_euclid(n),
+            Point::Identity => false,
         };
 
-        intermediate_values.generated_point = Some(random_point.clone());
-
-        if r == random_point.x {
-            intermediate_values.valid = true;
-            intermediate_values
-        } else {
-            intermediate_values
-        }
+        intermediate_values
     }
 }
 
@@ -144,18 +153,21 @@ mod test {
     macro_rules! test_point_generation {
         ($config:expr, $k:expr, $expected_x:expr, $expected_y:expr) => {{
             let curve = elliptic_curve::Curve::new($config.a, $config.b, $config.p);
-            let k = numeric::FromStrRadix::from_str_radix($k, 10).unwrap();
-            let result = curve.mul(&$config.g, &k).unwrap();
-            let x = result.x.to_str_radix(16);
-            let y = result.y.to_str_radix(16);
-
-            assert_eq!($expected_x.to_lowercase(), x.to_lowercase());
-            assert_eq!($expected_y.to_lowercase(), y.to_lowercase());
+            let k = $k.parse().unwrap();
+            let point = curve.mul(&$config.g, &k);
+            assert_eq!(curve.mul_affine(&$config.g, &k), point);
+            assert_eq!(curve.mul_ladder(&$config.g, &k), point);
+
+            let elliptic_curve::Point::Affine { x, y } = point else {
+                panic!("k·G should not be the point at infinity");
+            };
+            assert_eq!(Ok(x), $expected_x.parse());
+            assert_eq!(Ok(y), $expected_y.parse());
         }};
     }
 
-    pub struct SignTest<'a, T> {
-        pub config: Config<T>,
+    pub struct SignTest<'a, T, R> {
+        pub config: Config<T, R>,
         pub private_key: &'a str,
         pub public_key: (&'a str, &'a str),
         pub k: &'a str,
@@ -166,28 +178,28 @@ mod test {
     #[macro_export]
     macro_rules! test_sign {
         ($test: expr) => {{
-            let ecdsa = crate::Ecdsa::new($test.config);
+            let ecdsa = $crate::Ecdsa::with_generator_table($test.config);
 
-            let k = numeric::FromStrRadix::from_str_radix($test.k, 16).unwrap();
-            let private_key = numeric::FromStrRadix::from_str_radix($test.private_key, 16).unwrap();
-            let hash = numeric::FromStrRadix::from_str_radix($test.hash, 16).unwrap();
+            let k = $test.k.parse().unwrap();
+            let private_key = $test.private_key.parse().unwrap();
+            let hash = $test.hash.parse().unwrap();
 
             let signature = ecdsa.sign(&k, &private_key, &hash).unwrap().signature;
-            assert_eq!(
-                $test.signature.0.to_uppercase(),
-                signature.0.to_str_radix(16).to_uppercase()
-            );
-            assert_eq!(
-                $test.signature.1.to_uppercase(),
-                signature.1.to_str_radix(16).to_uppercase()
-            );
+            assert_eq!(Ok(signature.0), $test.signature.0.parse());
+            assert_eq!(Ok(signature.1), $test.signature.1.parse());
 
             let public_key = elliptic_curve::Point::new(
-                numeric::FromStrRadix::from_str_radix($test.public_key.0, 16).unwrap(),
-                numeric::FromStrRadix::from_str_radix($test.public_key.1, 16).unwrap(),
+                $test.public_key.0.parse().unwrap(),
+                $test.public_key.1.parse().unwrap(),
             );
 
             assert!(ecdsa.verify(&public_key, &hash, &signature).valid);
+
+            let curve = elliptic_curve::Curve::new($test.config.a, $test.config.b, $test.config.p);
+            for compressed in [false, true] {
+                let encoded = curve.encode_point(&public_key, compressed);
+                assert_eq!(Ok(public_key.clone()), curve.decode_point(&encoded));
+            }
         }};
     }
 }
@@ -1,7 +1,7 @@
 extern crate console_error_panic_hook;
 extern crate wasm_bindgen;
 
-use core::{array::TryFromSliceError, cmp::min, fmt::LowerHex};
+use core::{cmp::min, fmt::LowerHex};
 
 use alloc::{boxed::Box, format, string::String, vec::Vec};
 use big_num::{
@@ -9,9 +9,10 @@ use big_num::{
     ParseBigIntError,
 };
 use curves::{Config, InvalidGeneratorError, P256, P384, P521};
-use elliptic_curve::{Numeric, Point};
-use modular::Widened;
-use numeric::{FromBeBytes, FromStrRadix, Widen};
+use elliptic_curve::{Curve, DecodingError, Numeric, Point};
+use modular::{ModularArithmetic, P256Reducer, P384Reducer, P521Reducer, Reducer, Widened};
+use number_theory::is_prime;
+use numeric::{FromBeBytes, FromBeSlice, FromStrRadix, ToBeSlice, Widen};
 use sha::{
     sha1::Sha1, sha224::Sha224, sha256::Sha256, sha384::Sha384, sha512::Sha512, HashingAlgorithm,
 };
@@ -33,71 +34,117 @@ pub struct PointHex {
     pub y: String,
 }
 
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Default)]
+pub struct EncodedPointHex {
+    pub uncompressed: String,
+    pub compressed: String,
+}
+
 #[wasm_bindgen]
-#[derive(Debug)]
-pub enum SigningError {
-    ParseBigInt,
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SigningErrorKind {
+    EmptyNumber,
+    InvalidRadix,
+    InvalidDigit,
+    NumberTooLarge,
     MessageTooLong,
     InvalidPoint,
     NoInvK,
     ZeroingK,
 }
 
-impl From<ParseBigIntError> for SigningError {
-    fn from(_: ParseBigIntError) -> Self {
-        SigningError::ParseBigInt
-    }
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EcdsaCustomErrorKind {
+    EmptyNumber,
+    InvalidRadix,
+    InvalidDigit,
+    NumberTooLarge,
+    NonPrimeModulus,
+    NonPrimeOrder,
+    InvalidGenerator,
 }
 
-impl From<TryFromSliceError> for SigningError {
-    fn from(_: TryFromSliceError) -> Self {
-        SigningError::MessageTooLong
-    }
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerifyingErrorKind {
+    EmptyNumber,
+    InvalidRadix,
+    InvalidDigit,
+    NumberTooLarge,
+    MessageTooLong,
+    InvalidPublicKey,
 }
 
-impl From<crate::SigningError> for SigningError {
-    fn from(value: crate::SigningError) -> Self {
-        match value {
-            crate::SigningError::InvalidPoint => SigningError::InvalidPoint,
-            crate::SigningError::NoInvK => SigningError::NoInvK,
-            crate::SigningError::ZeroingK => SigningError::ZeroingK,
-        }
-    }
-}
+// wasm_bindgen enums cannot carry data, so each error pairs its kind with the position of the
+// offending character for the errors that have one
+macro_rules! impl_error {
+    ($($t:ident => $kind:ident),*) => {
+        $(
+            #[wasm_bindgen]
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            pub struct $t {
+                pub kind: $kind,
+                pub position: Option<usize>,
+            }
 
-#[wasm_bindgen]
-pub enum EcdsaCustomError {
-    ParseBigInt,
-    InvalidGenerator,
-}
+            impl From<$kind> for $t {
+                fn from(kind: $kind) -> Self {
+                    $t {
+                        kind,
+                        position: None,
+                    }
+                }
+            }
 
-impl From<InvalidGeneratorError> for EcdsaCustomError {
-    fn from(_: InvalidGeneratorError) -> Self {
-        EcdsaCustomError::InvalidGenerator
-    }
+            impl From<ParseBigIntError> for $t {
+                fn from(error: ParseBigIntError) -> Self {
+                    let (kind, position) = match error {
+                        ParseBigIntError::Empty => ($kind::EmptyNumber, None),
+                        ParseBigIntError::InvalidRadix(_) => ($kind::InvalidRadix, None),
+                        ParseBigIntError::InvalidChar { position } => {
+                            ($kind::InvalidDigit, Some(position))
+                        }
+                        ParseBigIntError::Overflow { position } => {
+                            ($kind::NumberTooLarge, Some(position))
+                        }
+                    };
+
+                    $t { kind, position }
+                }
+            }
+        )*
+    };
 }
 
-impl From<ParseBigIntError> for EcdsaCustomError {
-    fn from(_: ParseBigIntError) -> Self {
-        EcdsaCustomError::ParseBigInt
-    }
-}
+impl_error!(
+    SigningError => SigningErrorKind,
+    EcdsaCustomError => EcdsaCustomErrorKind,
+    VerifyingError => VerifyingErrorKind
+);
 
-#[wasm_bindgen]
-pub enum VerifyingError {
-    ParseBigInt,
-    MessageTooLong,
+impl From<crate::SigningError> for SigningError {
+    fn from(value: crate::SigningError) -> Self {
+        let kind = match value {
+            crate::SigningError::InvalidPoint => SigningErrorKind::InvalidPoint,
+            crate::SigningError::NoInvK => SigningErrorKind::NoInvK,
+            crate::SigningError::ZeroingK => SigningErrorKind::ZeroingK,
+        };
+
+        kind.into()
+    }
 }
 
-impl From<ParseBigIntError> for VerifyingError {
-    fn from(_: ParseBigIntError) -> Self {
-        VerifyingError::ParseBigInt
+impl From<InvalidGeneratorError> for EcdsaCustomError {
+    fn from(_: InvalidGeneratorError) -> Self {
+        EcdsaCustomErrorKind::InvalidGenerator.into()
     }
 }
 
-impl From<TryFromSliceError> for VerifyingError {
-    fn from(_: TryFromSliceError) -> Self {
-        VerifyingError::MessageTooLong
+impl From<DecodingError> for VerifyingError {
+    fn from(_: DecodingError) -> Self {
+        VerifyingErrorKind::InvalidPublicKey.into()
     }
 }
 
@@ -123,6 +170,7 @@ pub struct VerifyingIntermediateValuesHex {
     pub u1: String,
     pub u2: String,
     pub generated_point: PointHex,
+    pub public_key: EncodedPointHex,
     pub valid: bool,
 }
 trait DynHashingAlgorithm {
@@ -192,7 +240,17 @@ impl EcdsaCustom {
         let gy = U640::from_be_hex(gy)?;
         let n = U640::from_be_hex(n)?;
 
+        if !is_prime(&p) {
+            return Err(EcdsaCustomErrorKind::NonPrimeModulus.into());
+        }
+        if !is_prime(&n) {
+            return Err(EcdsaCustomErrorKind::NonPrimeOrder.into());
+        }
+
         let config = Config::new(p, a, b, Point::new(gx, gy), n)?;
+        if !config.generator_has_order_n() {
+            return Err(EcdsaCustomErrorKind::InvalidGenerator.into());
+        }
 
         Ok(Self {
             ecdsa: Ecdsa::new(config),
@@ -200,7 +258,7 @@ impl EcdsaCustom {
     }
 
     pub fn sign(
-        &mut self,
+        &self,
         k: &str,
         key: &str,
         message: &str,
@@ -220,25 +278,44 @@ impl EcdsaCustom {
     ) -> Result<VerifyingIntermediateValuesHex, VerifyingError> {
         verify(&self.ecdsa, x, y, r, s, message, hashing_algorithm_type)
     }
+
+    pub fn verify_encoded(
+        &self,
+        key: &str,
+        r: &str,
+        s: &str,
+        message: &str,
+        hashing_algorithm_type: HashingAlgorithmType,
+    ) -> Result<VerifyingIntermediateValuesHex, VerifyingError> {
+        verify_encoded(&self.ecdsa, key, r, s, message, hashing_algorithm_type)
+    }
+
+    pub fn encode_public_key(&self, x: &str, y: &str) -> Result<EncodedPointHex, VerifyingError> {
+        encode_public_key(&self.ecdsa, x, y)
+    }
+
+    pub fn decode_public_key(&self, key: &str) -> Result<PointHex, VerifyingError> {
+        decode_public_key(&self.ecdsa, key)
+    }
 }
 
 macro_rules! impl_ecdsa {
-    ($name:ident, $curve:ident, $t:ty) => {
+    ($name:ident, $curve:ident, $t:ty, $reducer:ty) => {
         #[wasm_bindgen]
         pub struct $name {
-            ecdsa: Ecdsa<$t>,
+            ecdsa: Ecdsa<$t, $reducer>,
         }
 
         #[wasm_bindgen]
         impl $name {
             pub fn new() -> Self {
                 Self {
-                    ecdsa: Ecdsa::new($curve),
+                    ecdsa: Ecdsa::with_generator_table($curve),
                 }
             }
 
             pub fn sign(
-                &mut self,
+                &self,
                 k: &str,
                 key: &str,
                 message: &str,
@@ -258,23 +335,50 @@ macro_rules! impl_ecdsa {
             ) -> Result<VerifyingIntermediateValuesHex, VerifyingError> {
                 verify(&self.ecdsa, x, y, r, s, message, hashing_algorithm_type)
             }
+
+            pub fn verify_encoded(
+                &self,
+                key: &str,
+                r: &str,
+                s: &str,
+                message: &str,
+                hashing_algorithm_type: HashingAlgorithmType,
+            ) -> Result<VerifyingIntermediateValuesHex, VerifyingError> {
+                verify_encoded(&self.ecdsa, key, r, s, message, hashing_algorithm_type)
+            }
+
+            pub fn encode_public_key(
+                &self,
+                x: &str,
+                y: &str,
+            ) -> Result<EncodedPointHex, VerifyingError> {
+                encode_public_key(&self.ecdsa, x, y)
+            }
+
+            pub fn decode_public_key(&self, key: &str) -> Result<PointHex, VerifyingError> {
+                decode_public_key(&self.ecdsa, key)
+            }
         }
     };
 }
 
-impl_ecdsa!(EcdsaP256, P256, U256);
-impl_ecdsa!(EcdsaP384, P384, U384);
-impl_ecdsa!(EcdsaP521, P521, U640);
+impl_ecdsa!(EcdsaP256, P256, U256, P256Reducer);
+impl_ecdsa!(EcdsaP384, P384, U384, P384Reducer);
+impl_ecdsa!(EcdsaP521, P521, U640, P521Reducer);
 
-pub fn sign<T: Numeric, const N: usize>(
-    ecdsa: &Ecdsa<T>,
+pub fn sign<T, R: Reducer<T>, const N: usize>(
+    ecdsa: &Ecdsa<T, R>,
     k: &str,
     key: &str,
     message: &str,
     hashing_algorithm_type: HashingAlgorithmType,
 ) -> Result<SigningIntermediateValuesHex, SigningError>
 where
-    T: FromBeBytes<Bytes = [u8; N]> + LowerHex + FromStrRadix<Error = ParseBigIntError>,
+    T: Numeric
+        + FromBeBytes<Bytes = [u8; N]>
+        + FromBeSlice
+        + LowerHex
+        + FromStrRadix<Error = ParseBigIntError>,
     <T as Widen>::Output: Widened<T>,
 {
     console_error_panic_hook::set_once();
@@ -287,17 +391,15 @@ where
             hasher.update(message_bytes);
             let digest = hasher.digest();
             intermediate_values.hash = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
-            let mut truncated = [0u8; N];
-            let start = N.saturating_sub(digest.len());
-            truncated[start..].copy_from_slice(&digest[..min(N, digest.len())]);
-            T::from_be_bytes(&truncated)
+            T::from_be_slice(&digest[..min(N, digest.len())])
+                .expect("the truncated digest fits in T")
         }
         None => {
             intermediate_values.hash = message_bytes
                 .iter()
                 .map(|byte| format!("{:02x}", byte))
                 .collect();
-            T::from_be_bytes(message_bytes.try_into()?)
+            T::from_be_slice(message_bytes).ok_or(SigningErrorKind::MessageTooLong)?
         }
     };
     intermediate_values.truncated_hash = format!("{:x}", hash);
@@ -307,10 +409,12 @@ where
 
     let signing_intermediate_values = ecdsa.sign(&k, &key, &hash)?;
 
-    intermediate_values.generated_point = PointHex {
-        x: format!("{:x}", signing_intermediate_values.generated_point.x),
-        y: format!("{:x}", signing_intermediate_values.generated_point.y),
-    };
+    if let Point::Affine { x, y } = signing_intermediate_values.generated_point {
+        intermediate_values.generated_point = PointHex {
+            x: format!("{:x}", x),
+            y: format!("{:x}", y),
+        };
+    }
 
     intermediate_values.signature = SignatureHex {
         r: format!("{:x}", signing_intermediate_values.signature.0),
@@ -320,8 +424,8 @@ where
     Ok(intermediate_values)
 }
 
-pub fn verify<T: Numeric, const N: usize>(
-    ecdsa: &Ecdsa<T>,
+pub fn verify<T, R: Reducer<T>, const N: usize>(
+    ecdsa: &Ecdsa<T, R>,
     x: &str,
     y: &str,
     r: &str,
@@ -330,11 +434,138 @@ pub fn verify<T: Numeric, const N: usize>(
     hashing_algorithm_type: HashingAlgorithmType,
 ) -> Result<VerifyingIntermediateValuesHex, VerifyingError>
 where
-    T: FromBeBytes<Bytes = [u8; N]> + LowerHex + FromStrRadix<Error = ParseBigIntError>,
+    T: Numeric
+        + FromBeBytes<Bytes = [u8; N]>
+        + FromBeSlice
+        + ToBeSlice
+        + LowerHex
+        + FromStrRadix<Error = ParseBigIntError>,
     <T as Widen>::Output: Widened<T>,
 {
-    let mut intermediate_values = VerifyingIntermediateValuesHex::default();
     let key = Point::new(T::from_str_radix(x, 16)?, T::from_str_radix(y, 16)?);
+    verify_key(ecdsa, key, r, s, message, hashing_algorithm_type)
+}
+
+pub fn verify_encoded<T, R: Reducer<T>, const N: usize>(
+    ecdsa: &Ecdsa<T, R>,
+    key: &str,
+    r: &str,
+    s: &str,
+    message: &str,
+    hashing_algorithm_type: HashingAlgorithmType,
+) -> Result<VerifyingIntermediateValuesHex, VerifyingError>
+where
+    T: Numeric
+        + FromBeBytes<Bytes = [u8; N]>
+        + FromBeSlice
+        + ToBeSlice
+        + LowerHex
+        + FromStrRadix<Error = ParseBigIntError>,
+    <T as Widen>::Output: Widened<T>,
+{
+    let curve = ecdsa.config.get_curve();
+    let key = curve.decode_point(&decode_hex(key)?)?;
+    verify_key(ecdsa, key, r, s, message, hashing_algorithm_type)
+}
+
+pub fn encode_public_key<T, R: Reducer<T>>(
+    ecdsa: &Ecdsa<T, R>,
+    x: &str,
+    y: &str,
+) -> Result<EncodedPointHex, VerifyingError>
+where
+    T: Numeric + FromBeSlice + ToBeSlice + FromStrRadix<Error = ParseBigIntError>,
+    <T as Widen>::Output: Widened<T>,
+{
+    let curve = ecdsa.config.get_curve();
+    let key = Point::new(T::from_str_radix(x, 16)?, T::from_str_radix(y, 16)?);
+
+    if key.is_identity() || !curve.is_valid_point(&key) {
+        return Err(VerifyingErrorKind::InvalidPublicKey.into());
+    }
+
+    Ok(encode_point_hex(&curve, &key))
+}
+
+pub fn decode_public_key<T, R: Reducer<T>>(
+    ecdsa: &Ecdsa<T, R>,
+    key: &str,
+) -> Result<PointHex, VerifyingError>
+where
+    T: Numeric + FromBeSlice + ToBeSlice + LowerHex,
+    <T as Widen>::Output: Widened<T>,
+{
+    let curve = ecdsa.config.get_curve();
+
+    match curve.decode_point(&decode_hex(key)?)? {
+        Point::Affine { x, y } => Ok(PointHex {
+            x: format!("{:x}", x),
+            y: format!("{:x}", y),
+        }),
+        Point::Identity => Err(VerifyingErrorKind::InvalidPublicKey.into()),
+    }
+}
+
+fn encode_point_hex<T, R: ModularArithmetic<T>>(
+    curve: &Curve<T, R>,
+    p: &Point<T>,
+) -> EncodedPointHex
+where
+    T: Numeric + FromBeSlice + ToBeSlice,
+    <T as Widen>::Output: Widened<T>,
+{
+    EncodedPointHex {
+        uncompressed: encode_hex(&curve.encode_point(p, false)),
+        compressed: encode_hex(&curve.encode_point(p, true)),
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(src: &str) -> Result<Vec<u8>, VerifyingError> {
+    if !src.len().is_multiple_of(2) {
+        return Err(VerifyingErrorKind::InvalidPublicKey.into());
+    }
+
+    if let Some(position) = src.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(VerifyingError {
+            kind: VerifyingErrorKind::InvalidDigit,
+            position: Some(position),
+        });
+    }
+
+    // every character is an ASCII hex digit, so the pairs are valid bytes
+    Ok((0..src.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&src[i..i + 2], 16).expect("hex digits"))
+        .collect())
+}
+
+fn verify_key<T, R: Reducer<T>, const N: usize>(
+    ecdsa: &Ecdsa<T, R>,
+    key: Point<T>,
+    r: &str,
+    s: &str,
+    message: &str,
+    hashing_algorithm_type: HashingAlgorithmType,
+) -> Result<VerifyingIntermediateValuesHex, VerifyingError>
+where
+    T: Numeric
+        + FromBeBytes<Bytes = [u8; N]>
+        + FromBeSlice
+        + ToBeSlice
+        + LowerHex
+        + FromStrRadix<Error = ParseBigIntError>,
+    <T as Widen>::Output: Widened<T>,
+{
+    let mut intermediate_values = VerifyingIntermediateValuesHex::default();
+
+    let curve = ecdsa.config.get_curve();
+    if !key.is_identity() && curve.is_valid_point(&key) {
+        intermediate_values.public_key = encode_point_hex(&curve, &key);
+    }
 
     let hasher = hashing_algorithm_type.to_hashing_algorithm();
 
@@ -344,17 +575,15 @@ where
             hasher.update(message_bytes);
             let digest = hasher.digest();
             intermediate_values.hash = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
-            let mut truncated = [0u8; N];
-            let start = N.saturating_sub(digest.len());
-            truncated[start..].copy_from_slice(&digest[..min(N, digest.len())]);
-            T::from_be_bytes(&truncated)
+            T::from_be_slice(&digest[..min(N, digest.len())])
+                .expect("the truncated digest fits in T")
         }
         None => {
             intermediate_values.hash = message_bytes
                 .iter()
                 .map(|byte| format!("{:02x}", byte))
                 .collect();
-            T::from_be_bytes(message_bytes.try_into()?)
+            T::from_be_slice(message_bytes).ok_or(VerifyingErrorKind::MessageTooLong)?
         }
     };
 
@@ -367,10 +596,10 @@ where
         intermediate_values.u2 = format!("{:x}", u.1);
     }
 
-    if let Some(generated_point) = verifying_intermediate_values.generated_point {
+    if let Some(Point::Affine { x, y }) = verifying_intermediate_values.generated_point {
         intermediate_values.generated_point = PointHex {
-            x: format!("{:x}", generated_point.x),
-            y: format!("{:x}", generated_point.y),
+            x: format!("{:x}", x),
+            y: format!("{:x}", y),
         };
     }
 
@@ -381,7 +610,7 @@ where
 
 #[test]
 fn test1() {
-    let mut ecdsa = EcdsaP256::new();
+    let ecdsa = EcdsaP256::new();
     let intermediate_values = ecdsa.sign(
         "882905F1227FD620FBF2ABF21244F0BA83D0DC3A9103DBBEE43A1FB858109DB4",
         "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
@@ -391,3 +620,76 @@ fn test1() {
 
     assert_eq!("", intermediate_values.unwrap().hash)
 }
+
+#[test]
+fn sign_reports_parse_errors() {
+    let ecdsa = EcdsaP256::new();
+
+    let too_large = ecdsa.sign("1", &"f".repeat(65), "sample", HashingAlgorithmType::Sha256);
+    let too_large = too_large.err().unwrap();
+    assert_eq!(SigningErrorKind::NumberTooLarge, too_large.kind);
+    assert_eq!(Some(64), too_large.position);
+
+    let invalid = ecdsa.sign("1", "12xyz", "sample", HashingAlgorithmType::Sha256);
+    let invalid = invalid.err().unwrap();
+    assert_eq!(SigningErrorKind::InvalidDigit, invalid.kind);
+    assert_eq!(Some(2), invalid.position);
+
+    let empty = ecdsa.sign("", "1", "sample", HashingAlgorithmType::Sha256);
+    let empty = empty.err().unwrap();
+    assert_eq!(SigningErrorKind::EmptyNumber, empty.kind);
+    assert_eq!(None, empty.position);
+}
+
+#[test]
+fn sign_unhashed_message_of_any_fitting_length() {
+    let ecdsa = EcdsaP256::new();
+    let k = "882905F1227FD620FBF2ABF21244F0BA83D0DC3A9103DBBEE43A1FB858109DB4";
+    let key = "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721";
+
+    let short = ecdsa.sign(k, key, "sample", HashingAlgorithmType::None);
+    assert_eq!("73616d706c65", short.unwrap().truncated_hash);
+
+    let long = ecdsa.sign(k, key, &"a".repeat(33), HashingAlgorithmType::None);
+    assert_eq!(SigningErrorKind::MessageTooLong, long.err().unwrap().kind);
+}
+
+#[test]
+fn custom_curve_requires_prime_modulus_and_order() {
+    // y^2 = x^3 + 2x + 2 over F_17, where (5, 1) generates a group of order 19
+    assert!(EcdsaCustom::new("11", "2", "2", "5", "1", "13").is_ok());
+
+    let modulus = EcdsaCustom::new("f", "2", "2", "5", "1", "13");
+    assert_eq!(
+        EcdsaCustomErrorKind::NonPrimeModulus,
+        modulus.err().unwrap().kind
+    );
+
+    let order = EcdsaCustom::new("11", "2", "2", "5", "1", "12");
+    assert_eq!(
+        EcdsaCustomErrorKind::NonPrimeOrder,
+        order.err().unwrap().kind
+    );
+
+    // 17 is prime, but not the order of (5, 1)
+    let generator = EcdsaCustom::new("11", "2", "2", "5", "1", "11");
+    assert_eq!(
+        EcdsaCustomErrorKind::InvalidGenerator,
+        generator.err().unwrap().kind
+    );
+}
+
+#[test]
+fn verify_encoded_reports_the_invalid_digit() {
+    let ecdsa = EcdsaP256::new();
+
+    let error = ecdsa.verify_encoded("04ab0g", "1", "1", "sample", HashingAlgorithmType::Sha256);
+    let error = error.err().unwrap();
+    assert_eq!(VerifyingErrorKind::InvalidDigit, error.kind);
+    assert_eq!(Some(5), error.position);
+
+    let error = ecdsa.verify("1", "1", "1", "xyz", "sample", HashingAlgorithmType::Sha256);
+    let error = error.err().unwrap();
+    assert_eq!(VerifyingErrorKind::InvalidDigit, error.kind);
+    assert_eq!(Some(0), error.position);
+}
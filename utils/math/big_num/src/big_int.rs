@@ -0,0 +1,364 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self},
+    ops::Neg,
+    str::FromStr,
+};
+
+use alloc::{format, string::String};
+use numeric::{CheckedAdd, CheckedMul, CheckedSub, FromStrRadix, One, RemEuclid, ToStrRadix, Zero};
+
+use crate::{BigUint, ParseBigIntError};
+
+// Sign-magnitude integer. Division truncates towards zero and the remainder takes the sign of the
+// dividend, like Rust's primitive integers.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct BigInt<const N: usize> {
+    negative: bool,
+    magnitude: BigUint<N>,
+}
+
+impl<const N: usize> BigInt<N> {
+    pub fn new(negative: bool, magnitude: BigUint<N>) -> Self {
+        // zero is never negative, so there is a single representation of it
+        BigInt {
+            negative: negative && magnitude != BigUint::default(),
+            magnitude,
+        }
+    }
+
+    pub fn magnitude(&self) -> BigUint<N> {
+        self.magnitude
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn div_rem(&self, rhs: Self) -> (Self, Self) {
+        let (quotient, remainder) = if rhs.magnitude > self.magnitude {
+            (BigUint::default(), self.magnitude)
+        } else {
+            self.magnitude.div_rem(rhs.magnitude)
+        };
+
+        (
+            BigInt::new(self.negative != rhs.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        )
+    }
+}
+
+impl<const N: usize> From<BigUint<N>> for BigInt<N> {
+    fn from(magnitude: BigUint<N>) -> Self {
+        BigInt::new(false, magnitude)
+    }
+}
+
+impl<const N: usize> From<u8> for BigInt<N> {
+    fn from(value: u8) -> Self {
+        BigInt::new(false, BigUint::from(value))
+    }
+}
+
+// The sign goes before the prefix and padding, so the magnitude is written as for BigUint
+macro_rules! impl_fmt {
+    ($($fmt:ident, $radix:expr, $prefix:expr, $uppercase:expr;)*) => {
+        $(
+            impl<const N: usize> fmt::$fmt for BigInt<N> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.magnitude.fmt_radix(f, !self.negative, $radix, $prefix, $uppercase)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt!(
+    Display, 10, "", false;
+    Binary, 2, "0b", false;
+    Octal, 8, "0o", false;
+    LowerHex, 16, "0x", false;
+    UpperHex, 16, "0x", true;
+);
+
+impl<const N: usize> FromStrRadix for BigInt<N> {
+    type Error = ParseBigIntError;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::Error> {
+        match src.strip_prefix('-') {
+            Some(magnitude) => BigUint::from_str_radix(magnitude, radix)
+                .map(|magnitude| BigInt::new(true, magnitude))
+                .map_err(|error| error.offset(1)),
+            None => Ok(BigInt::new(false, BigUint::from_str_radix(src, radix)?)),
+        }
+    }
+}
+
+impl<const N: usize> ToStrRadix for BigInt<N> {
+    fn to_str_radix(&self, radix: u32) -> String {
+        let magnitude = self.magnitude.to_str_radix(radix);
+
+        if self.negative {
+            format!("-{}", magnitude)
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl<const N: usize> FromStr for BigInt<N> {
+    type Err = ParseBigIntError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        match src.strip_prefix('-') {
+            Some(magnitude) => BigUint::from_str(magnitude)
+                .map(|magnitude| BigInt::new(true, magnitude))
+                .map_err(|error| error.offset(1)),
+            None => Ok(BigInt::new(false, BigUint::from_str(src)?)),
+        }
+    }
+}
+
+impl<const N: usize> Neg for BigInt<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl<const N: usize> core::ops::Add for BigInt<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("integer overflow")
+    }
+}
+
+impl<const N: usize> core::ops::Sub for BigInt<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("integer overflow")
+    }
+}
+
+impl<const N: usize> core::ops::Mul for BigInt<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("integer overflow")
+    }
+}
+
+impl<const N: usize> core::ops::Div for BigInt<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<const N: usize> core::ops::Rem for BigInt<N> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<const N: usize> core::cmp::PartialOrd for BigInt<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> core::cmp::Ord for BigInt<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl<const N: usize> One for BigInt<N> {
+    fn one() -> Self {
+        BigInt::new(false, BigUint::one())
+    }
+}
+
+impl<const N: usize> Zero for BigInt<N> {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+}
+
+// Always in [0, |rhs|), matching the primitive integers
+impl<const N: usize> RemEuclid for BigInt<N> {
+    fn rem_euclid(&self, rhs: &Self) -> Self {
+        let remainder = *self % *rhs;
+
+        if remainder.negative {
+            BigInt::new(false, rhs.magnitude - remainder.magnitude)
+        } else {
+            remainder
+        }
+    }
+}
+
+impl<const N: usize> CheckedAdd for BigInt<N> {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        if self.negative == v.negative {
+            let magnitude = self.magnitude.checked_add(&v.magnitude)?;
+            Some(BigInt::new(self.negative, magnitude))
+        } else if self.magnitude >= v.magnitude {
+            Some(BigInt::new(self.negative, self.magnitude - v.magnitude))
+        } else {
+            Some(BigInt::new(v.negative, v.magnitude - self.magnitude))
+        }
+    }
+}
+
+impl<const N: usize> CheckedSub for BigInt<N> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.checked_add(&-*v)
+    }
+}
+
+impl<const N: usize> CheckedMul for BigInt<N> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_mul(&v.magnitude)?;
+        Some(BigInt::new(self.negative != v.negative, magnitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Word;
+
+    fn int(n: i64) -> BigInt<4> {
+        let magnitude = n.unsigned_abs();
+        let words = [magnitude as u32, (magnitude >> 32) as u32];
+        BigInt::new(n < 0, BigUint::from_words(words))
+    }
+
+    #[test]
+    fn negative_zero_is_zero() {
+        assert_eq!(BigInt::<4>::zero(), -BigInt::zero());
+        assert_eq!(BigInt::<4>::zero(), BigInt::new(true, BigUint::default()));
+        assert!(!(-BigInt::<4>::zero()).is_negative());
+    }
+
+    #[test]
+    fn add_and_sub_mixed_signs() {
+        for a in [-300, -7, -1, 0, 1, 7, 300] {
+            for b in [-300, -7, -1, 0, 1, 7, 300] {
+                assert_eq!(int(a + b), int(a) + int(b), "{} + {}", a, b);
+                assert_eq!(int(a - b), int(a) - int(b), "{} - {}", a, b);
+                assert_eq!(int(a * b), int(a) * int(b), "{} * {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn div_and_rem_truncate_like_primitives() {
+        for a in [-300, -7, -1, 0, 1, 7, 300] {
+            for b in [-13, -7, -1, 1, 7, 13] {
+                assert_eq!(int(a / b), int(a) / int(b), "{} / {}", a, b);
+                assert_eq!(int(a % b), int(a) % int(b), "{} % {}", a, b);
+                assert_eq!(
+                    int(a.rem_euclid(b)),
+                    int(a).rem_euclid(&int(b)),
+                    "{} mod {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(int(-5) < int(-3));
+        assert!(int(-3) < int(0));
+        assert!(int(0) < int(3));
+        assert!(int(3) < int(5));
+        assert!(int(-5) < int(3));
+    }
+
+    #[test]
+    fn checked_add_overflow() {
+        let max = BigInt::from(BigUint::new([Word::MAX; 4]));
+
+        assert_eq!(None, max.checked_add(&BigInt::one()));
+        assert_eq!(None, (-max).checked_sub(&BigInt::one()));
+        assert_eq!(Some(BigInt::zero()), max.checked_add(&-max));
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let n: BigInt<4> = BigInt::from_str_radix("-12345678901234567890", 10).unwrap();
+
+        assert!(n.is_negative());
+        assert_eq!("-12345678901234567890", format!("{}", n));
+        assert_eq!("-ab54a98ceb1f0ad2", format!("{:x}", n));
+        assert_eq!("-AB54A98CEB1F0AD2", format!("{:X}", n));
+        assert_eq!("31", format!("{}", int(31)));
+        assert_eq!(n, BigInt::from_str_radix(&n.to_str_radix(16), 16).unwrap());
+    }
+
+    #[test]
+    fn str_radix_matches_primitives() {
+        for n in [i64::MIN + 1, -0xbeef, -36, -1, 0, 1, 35, 0xbeef, i64::MAX] {
+            for radix in [2, 8, 10, 16, 36] {
+                let digits = n.to_str_radix(radix);
+
+                assert_eq!(
+                    digits,
+                    int(n).to_str_radix(radix),
+                    "{} in base {}",
+                    n,
+                    radix
+                );
+                assert_eq!(Ok(int(n)), BigInt::from_str_radix(&digits, radix));
+                assert_eq!(Ok(n), i64::from_str_radix(&digits, radix));
+            }
+        }
+    }
+
+    #[test]
+    fn formatting_flags_and_from_str() {
+        let n = int(-0xbeef);
+
+        assert_eq!("-0xbeef", format!("{:#x}", n));
+        assert_eq!("-0x00BEEF", format!("{:#09X}", n));
+        assert_eq!("-0b1011111011101111", format!("{:#b}", n));
+        assert_eq!("+48879", format!("{:+}", -n));
+        assert_eq!("  -48879", format!("{:>8}", n));
+
+        assert_eq!(Ok(n), "-0xbeef".parse());
+        assert_eq!(Ok(n), "-48_879".parse());
+        assert_eq!(Ok(-n), "0o137357".parse());
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 3 }),
+            "-0b2".parse::<BigInt<4>>()
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_past_the_sign() {
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 3 }),
+            BigInt::<4>::from_str_radix("-12x", 10)
+        );
+        assert_eq!(
+            Err(ParseBigIntError::Empty),
+            BigInt::<4>::from_str_radix("-", 10)
+        );
+    }
+}
@@ -1,35 +1,87 @@
 #![no_std]
 
+mod big_int;
+mod limb;
+mod mul;
 pub mod types;
 
-use core::fmt::{self};
+pub use big_int::BigInt;
+pub use limb::{DoubleWord, Word};
+pub use mul::KARATSUBA_THRESHOLD;
+
+use core::{
+    fmt::{self},
+    str::FromStr,
+};
 
 use alloc::string::String;
 use numeric::{
-    Bit, CheckedAdd, CheckedMul, CheckedSub, FromStrRadix, LeadingZeros, One, RemEuclid, Zero,
+    Bit, BitLength, CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Choice,
+    ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CountOnes, FromStrRadix,
+    LeadingZeros, One, OverflowingAdd, OverflowingSub, Pow, RemEuclid, SetBit, ToStrRadix,
+    TrailingZeros, WideningMul, WrappingAdd, WrappingMul, WrappingSub, Zero,
 };
 use types::RADIX;
 extern crate alloc;
 
-#[derive(Debug)]
+// Positions are byte offsets into the parsed string
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ParseBigIntError {
-    InvalidChar,
+    Empty,
+    InvalidRadix(u32),
+    InvalidChar { position: usize },
+    Overflow { position: usize },
+}
+
+impl ParseBigIntError {
+    // Moves the position past a prefix that was stripped before parsing
+    pub(crate) fn offset(self, prefix: usize) -> Self {
+        match self {
+            ParseBigIntError::InvalidChar { position } => ParseBigIntError::InvalidChar {
+                position: position + prefix,
+            },
+            ParseBigIntError::Overflow { position } => ParseBigIntError::Overflow {
+                position: position + prefix,
+            },
+            error => error,
+        }
+    }
 }
 
 impl fmt::Display for ParseBigIntError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ParseBigIntError::InvalidChar => write!(
+            ParseBigIntError::Empty => write!(f, "cannot parse a number from an empty string"),
+            ParseBigIntError::InvalidRadix(radix) => {
+                write!(f, "radix {} is not in the range 2..=36", radix)
+            }
+            ParseBigIntError::InvalidChar { position } => write!(
                 f,
-                "a character was encountered in the string that does not match the given radix"
+                "the character at position {} does not match the given radix",
+                position
+            ),
+            ParseBigIntError::Overflow { position } => write!(
+                f,
+                "the number is too large for the type from the digit at position {} on",
+                position
             ),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+// Returned when a BigUint does not fit the primitive integer it is converted to
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct TryFromBigIntError;
+
+impl fmt::Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the number is too large for the target integer type")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub struct BigUint<const N: usize> {
-    limbs: [u32; N],
+    limbs: [Word; N],
 }
 
 impl<const N: usize> Default for BigUint<N> {
@@ -39,13 +91,90 @@ impl<const N: usize> Default for BigUint<N> {
 }
 
 impl<const N: usize> BigUint<N> {
-    pub const BYTES: usize = N * 4;
+    pub const BYTES: usize = N * (Word::BITS as usize / 8);
     pub const LIMBS: usize = N;
 
-    pub const fn new(limbs: [u32; N]) -> Self {
+    pub const fn new(limbs: [Word; N]) -> Self {
+        BigUint { limbs }
+    }
+
+    // Builds a value from 32-bit words, least significant first, so constants can be written once
+    // for every limb width. Limbs past the last word are zero.
+    pub const fn from_words<const M: usize>(words: [u32; M]) -> Self {
+        assert!(M * 32 <= N * Word::BITS as usize, "too many words");
+
+        match BigUint::checked_from_words(words) {
+            Some(n) => n,
+            None => unreachable!(),
+        }
+    }
+
+    // Like from_words, but takes any number of words and returns None if the value does not fit
+    pub const fn checked_from_words<const M: usize>(words: [u32; M]) -> Option<Self> {
+        let words_per_limb = Word::BITS as usize / 32;
+        let mut limbs = [0; N];
+        let mut i = 0;
+        while i < M {
+            if i / words_per_limb < N {
+                limbs[i / words_per_limb] |= (words[i] as Word) << (32 * (i % words_per_limb));
+            } else if words[i] != 0 {
+                return None;
+            }
+            i += 1;
+        }
+
+        Some(BigUint { limbs })
+    }
+
+    // Parses a big endian hex string in a const context, so constants can be written as the hex
+    // strings from the standards, which group the digits with spaces. Invalid digits and values
+    // that do not fit fail the compilation.
+    pub const fn from_be_hex_const(src: &str) -> Self {
+        let bytes = src.as_bytes();
+        let bits = Word::BITS as usize;
+        let mut limbs = [0; N];
+        // bit position of the next digit, counted from the least significant end
+        let mut shift = 0;
+        let mut i = bytes.len();
+        while i > 0 {
+            i -= 1;
+            let digit = match bytes[i] {
+                b' ' => continue,
+                byte @ b'0'..=b'9' => byte - b'0',
+                byte @ b'a'..=b'f' => byte - b'a' + 10,
+                byte @ b'A'..=b'F' => byte - b'A' + 10,
+                _ => panic!("invalid hex digit"),
+            };
+
+            if shift < N * bits {
+                limbs[shift / bits] |= (digit as Word) << (shift % bits);
+            } else {
+                assert!(digit == 0, "hex value does not fit");
+            }
+            shift += 4;
+        }
+        assert!(shift > 0, "empty hex string");
+
         BigUint { limbs }
     }
 
+    // The words have to cover the N limbs exactly
+    #[allow(clippy::unnecessary_cast)] // a no-op with u32 limbs
+    pub fn to_words<const M: usize>(&self) -> [u32; M] {
+        assert!(
+            M * 32 == N * Word::BITS as usize,
+            "word count does not match the limbs"
+        );
+
+        let words_per_limb = Word::BITS as usize / 32;
+        let mut words = [0; M];
+        for (i, word) in words.iter_mut().enumerate() {
+            *word = (self.limbs[i / words_per_limb] >> (32 * (i % words_per_limb))) as u32;
+        }
+
+        words
+    }
+
     fn digits(&self) -> usize {
         let mut digits = 0;
 
@@ -59,7 +188,7 @@ impl<const N: usize> BigUint<N> {
         digits
     }
 
-    fn from_u32(n: u32) -> Self {
+    fn from_limb(n: Word) -> Self {
         let mut limbs = [0; N];
         limbs[0] = n;
 
@@ -70,71 +199,99 @@ impl<const N: usize> BigUint<N> {
         BigUint::from_str_radix(src, 16)
     }
 
-    pub fn to_str_radix(&self, radix: u32) -> String {
-        let mut s = String::new();
-        let mut num = *self;
+    // Leaves width, fill, zero padding and the `#` prefix to the formatter, as the primitive
+    // integers do
+    pub(crate) fn fmt_radix(
+        &self,
+        f: &mut fmt::Formatter,
+        is_nonnegative: bool,
+        radix: u32,
+        prefix: &str,
+        uppercase: bool,
+    ) -> fmt::Result {
+        let digits = self.to_str_radix(radix);
+        let digits = if uppercase {
+            digits.to_uppercase()
+        } else {
+            digits
+        };
 
-        loop {
-            let (quotient, remainder) = num.div_u32(radix);
-            s.push(char::from_digit(remainder, radix).expect("asd"));
-            num = quotient;
+        f.pad_integral(is_nonnegative, prefix, &digits)
+    }
 
-            if quotient == BigUint::default() {
-                break;
-            };
+    // Underscores are skipped after the first digit when allowed, as in Rust literals
+    fn parse_digits(src: &str, radix: u32, underscores: bool) -> Result<Self, ParseBigIntError> {
+        if src.is_empty() {
+            return Err(ParseBigIntError::Empty);
         }
 
-        s.chars().rev().collect()
+        let mut num: BigUint<N> = BigUint::default();
+
+        for (position, char) in src.char_indices() {
+            if underscores && char == '_' && position > 0 {
+                continue;
+            }
+
+            let digit = char
+                .to_digit(radix)
+                .ok_or(ParseBigIntError::InvalidChar { position })?;
+            num = num
+                .checked_mul_limb(Word::from(radix))
+                .and_then(|num| num.checked_add_limb(Word::from(digit)))
+                .ok_or(ParseBigIntError::Overflow { position })?;
+        }
+
+        Ok(num)
     }
 
-    pub fn to_limbs(&self) -> [u32; N] {
+    pub fn to_limbs(&self) -> [Word; N] {
         self.limbs
     }
 
-    fn div_u32(&self, n: u32) -> (Self, u32) {
-        let n64 = u64::from(n);
+    fn div_limb(&self, n: Word) -> (Self, Word) {
+        let n_wide = DoubleWord::from(n);
         let mut k = 0;
         let mut w = [0; N];
 
         for i in (0..N).rev() {
-            let cur = u64::from(self.limbs[i]) + k * RADIX;
-            w[i] = (cur / n64) as u32;
-            k = cur % n64;
+            let cur = DoubleWord::from(self.limbs[i]) + k * RADIX;
+            w[i] = (cur / n_wide) as Word;
+            k = cur % n_wide;
         }
 
-        (BigUint::new(w), k as u32)
+        (BigUint::new(w), k as Word)
     }
 
-    fn mul_u32(&self, n: u32) -> Self {
+    fn checked_mul_limb(&self, n: Word) -> Option<Self> {
         let mut w = [0; N];
-        let n64 = u64::from(n);
+        let n_wide = DoubleWord::from(n);
         let mut k = 0;
 
         for i in 0..N {
-            let cur = k + u64::from(self.limbs[i]) * n64;
-            w[i] = (cur % RADIX) as u32;
+            let cur = k + DoubleWord::from(self.limbs[i]) * n_wide;
+            w[i] = (cur % RADIX) as Word;
             k = cur / RADIX;
         }
 
         if k > 0 {
-            panic!("integer overflow");
+            return None;
         }
 
-        BigUint::new(w)
+        Some(BigUint::new(w))
     }
 
-    fn add_u32(&self, n: u32) -> Self {
-        let n64 = u64::from(n);
+    fn checked_add_limb(&self, n: Word) -> Option<Self> {
+        let n_wide = DoubleWord::from(n);
         let mut w = [0; N];
         let mut k = 0;
 
         for j in 0..N {
-            let sum = u64::from(self.limbs[j]) + if j > 0 { 0 } else { n64 } + k;
-            w[j] = (sum % RADIX) as u32;
+            let sum = DoubleWord::from(self.limbs[j]) + if j > 0 { 0 } else { n_wide } + k;
+            w[j] = (sum % RADIX) as Word;
 
             if sum >= RADIX {
                 if j == (N - 1) {
-                    panic!("integer overflow");
+                    return None;
                 }
                 k = 1;
             } else {
@@ -142,10 +299,10 @@ impl<const N: usize> BigUint<N> {
             }
         }
 
-        BigUint::new(w)
+        Some(BigUint::new(w))
     }
 
-    fn shl_limb(&self, index: usize, shift: u32) -> u32 {
+    fn shl_limb(&self, index: usize, shift: u32) -> Word {
         let limb = self.limbs[index];
         if shift == 0 {
             return limb;
@@ -153,11 +310,112 @@ impl<const N: usize> BigUint<N> {
         let carry = if index == 0 {
             0
         } else {
-            self.limbs[index - 1] >> (32 - shift)
+            self.limbs[index - 1] >> (Word::BITS - shift)
         };
         (limb << shift) | carry
     }
 
+    fn checked_mul_schoolbook(&self, rhs: &Self) -> Option<Self> {
+        let mut w = [0; N];
+        let mut k = 0;
+
+        for j in 0..N {
+            k = 0;
+            if rhs.limbs[j] == 0 {
+                continue;
+            };
+            for i in 0..N {
+                if i + j >= N {
+                    // anything left for the limbs past N, including the carry, overflows
+                    if self.limbs[i] != 0 || k != 0 {
+                        return None;
+                    }
+                } else {
+                    let t = DoubleWord::from(self.limbs[i]) * DoubleWord::from(rhs.limbs[j])
+                        + DoubleWord::from(w[i + j])
+                        + k;
+                    w[i + j] = (t % RADIX) as Word;
+                    k = t / RADIX;
+                }
+            }
+        }
+
+        if k > 0 {
+            return None;
+        }
+
+        Some(BigUint::new(w))
+    }
+
+    // Row by row product, with the columns past N going into the high half
+    fn widening_mul_schoolbook(&self, rhs: &Self) -> (Self, Self) {
+        let mut low = [0; N];
+        let mut high = [0; N];
+
+        for (j, &b) in rhs.limbs.iter().enumerate() {
+            let mut carry = 0;
+
+            for (i, &a) in self.limbs.iter().enumerate() {
+                let limb = if i + j < N {
+                    &mut low[i + j]
+                } else {
+                    &mut high[i + j - N]
+                };
+                let t = DoubleWord::from(a) * DoubleWord::from(b) + DoubleWord::from(*limb) + carry;
+                *limb = t as Word;
+                carry = t >> Word::BITS;
+            }
+
+            // column N + j is untouched by the rows before
+            high[j] = carry as Word;
+        }
+
+        (BigUint::new(low), BigUint::new(high))
+    }
+
+    // Truncates a double width product, or None if it does not fit in N limbs
+    fn from_product(product: &[Word]) -> Option<Self> {
+        if product.iter().skip(N).any(|&limb| limb != 0) {
+            return None;
+        }
+
+        let mut limbs = [0; N];
+        let len = product.len().min(N);
+        limbs[..len].copy_from_slice(&product[..len]);
+
+        Some(BigUint::new(limbs))
+    }
+
+    pub fn checked_square(&self) -> Option<Self> {
+        self.checked_square_with_threshold(KARATSUBA_THRESHOLD)
+    }
+
+    // checked_mul and checked_square with the limb count at which Karatsuba takes over picked by
+    // the caller, to tune it for a target. The threshold must be at least 4.
+    pub fn checked_mul_with_threshold(&self, rhs: &Self, threshold: usize) -> Option<Self> {
+        let a = &self.limbs[..self.digits()];
+        let b = &rhs.limbs[..rhs.digits()];
+        if a == b {
+            return self.checked_square_with_threshold(threshold);
+        }
+
+        BigUint::from_product(&mul::mul(a, b, threshold))
+    }
+
+    pub fn checked_square_with_threshold(&self, threshold: usize) -> Option<Self> {
+        BigUint::from_product(&mul::square(&self.limbs[..self.digits()], threshold))
+    }
+
+    pub fn square(&self) -> Self {
+        self.checked_square().expect("integer overflow")
+    }
+
+    // Bits from the least significant up to the highest set one; reverse it to go from the top
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
+        let n = *self;
+        (0..n.bit_length()).map(move |i| n.bit(i))
+    }
+
     fn div_rem(&self, rhs: Self) -> (Self, Self) {
         let mut dividend = *self;
         let divisor = rhs;
@@ -171,8 +429,8 @@ impl<const N: usize> BigUint<N> {
             return (BigUint::default(), BigUint::default());
         };
         if divisor_digits == 1 {
-            let (quotient, remainder) = self.div_u32(divisor.limbs[0]);
-            return (quotient, BigUint::from_u32(remainder));
+            let (quotient, remainder) = self.div_limb(divisor.limbs[0]);
+            return (quotient, BigUint::from_limb(remainder));
         }
 
         let shift = rhs.limbs[divisor_digits - 1].leading_zeros();
@@ -190,7 +448,7 @@ impl<const N: usize> BigUint<N> {
                 if shift == 0 {
                     0
                 } else {
-                    dividend.limbs[dividend_digits - 1] >> (32 - shift)
+                    dividend.limbs[dividend_digits - 1] >> (Word::BITS - shift)
                 }
             } else {
                 dividend.shl_limb(divisor_digits + i, shift)
@@ -203,17 +461,17 @@ impl<const N: usize> BigUint<N> {
             };
 
             let dividend_msd_combined =
-                (u64::from(dividend_msd) * RADIX) + (u64::from(dividend_smsd));
-            let mut q_estimate = dividend_msd_combined / u64::from(divisor_msd);
-            let mut r_estimate = dividend_msd_combined % u64::from(divisor_msd);
+                (DoubleWord::from(dividend_msd) * RADIX) + (DoubleWord::from(dividend_smsd));
+            let mut q_estimate = dividend_msd_combined / DoubleWord::from(divisor_msd);
+            let mut r_estimate = dividend_msd_combined % DoubleWord::from(divisor_msd);
 
             loop {
                 if q_estimate >= RADIX
-                    || ((q_estimate * u64::from(divisor_smsd))
-                        > ((r_estimate * RADIX) | u64::from(dividend_tmsd)))
+                    || ((q_estimate * DoubleWord::from(divisor_smsd))
+                        > ((r_estimate * RADIX) | DoubleWord::from(dividend_tmsd)))
                 {
                     q_estimate -= 1;
-                    r_estimate += u64::from(divisor_msd);
+                    r_estimate += DoubleWord::from(divisor_msd);
                     if r_estimate < RADIX {
                         continue;
                     };
@@ -221,74 +479,121 @@ impl<const N: usize> BigUint<N> {
                 break;
             }
 
-            // Based on previous loop, q_estimate must fit within 32 bits now
-            let mut borrow = 0;
+            // Based on previous loop, q_estimate must fit within a limb now
+            let mut borrow = false;
             let mut carry = 0;
             for j in 0..divisor_digits {
-                let product = u64::from(divisor.limbs[j]) * q_estimate + carry;
-                carry = product >> 32;
-                let sub = i64::from(dividend.limbs[i + j]) - (product as u32 as i64) - borrow;
-                borrow = if sub < 0 { 1 } else { 0 };
-                dividend.limbs[i + j] = sub as u32;
+                let product = DoubleWord::from(divisor.limbs[j]) * q_estimate + carry;
+                carry = product >> Word::BITS;
+                let (sub, b1) = dividend.limbs[i + j].overflowing_sub(product as Word);
+                let (sub, b2) = sub.overflowing_sub(Word::from(borrow));
+                borrow = b1 || b2;
+                dividend.limbs[i + j] = sub;
             }
 
             if i + divisor_digits < N {
-                let sub = i64::from(dividend.limbs[i + divisor_digits]) - carry as i64 - borrow;
-                borrow = if sub < 0 { 1 } else { 0 };
-                dividend.limbs[i + divisor_digits] = sub as u32;
+                let (sub, b1) = dividend.limbs[i + divisor_digits].overflowing_sub(carry as Word);
+                let (sub, b2) = sub.overflowing_sub(Word::from(borrow));
+                borrow = b1 || b2;
+                dividend.limbs[i + divisor_digits] = sub;
             }
 
-            if borrow != 0 {
+            if borrow {
                 q_estimate -= 1;
                 carry = 0;
                 for j in 0..divisor_digits {
-                    let add =
-                        u64::from(dividend.limbs[i + j]) + u64::from(divisor.limbs[j]) + carry;
-                    carry = add >> 32;
-                    dividend.limbs[i + j] = add as u32;
+                    let add = DoubleWord::from(dividend.limbs[i + j])
+                        + DoubleWord::from(divisor.limbs[j])
+                        + carry;
+                    carry = add >> Word::BITS;
+                    dividend.limbs[i + j] = add as Word;
                 }
                 if i + divisor_digits < N {
                     dividend.limbs[i + divisor_digits] =
-                        (u64::from(dividend.limbs[i + divisor_digits]) + carry) as u32;
+                        (DoubleWord::from(dividend.limbs[i + divisor_digits]) + carry) as Word;
                 }
             }
 
-            quotient.limbs[i] = q_estimate as u32;
+            quotient.limbs[i] = q_estimate as Word;
         }
 
         (quotient, dividend)
     }
 }
 
-impl<const N: usize> fmt::LowerHex for BigUint<N> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let hex_str = self.to_str_radix(16);
-        write!(f, "{}", hex_str)
-    }
+macro_rules! impl_fmt {
+    ($($fmt:ident, $radix:expr, $prefix:expr, $uppercase:expr;)*) => {
+        $(
+            impl<const N: usize> fmt::$fmt for BigUint<N> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.fmt_radix(f, true, $radix, $prefix, $uppercase)
+                }
+            }
+        )*
+    };
 }
 
-impl<const N: usize> fmt::UpperHex for BigUint<N> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let hex_str = self.to_str_radix(16);
-        write!(f, "{}", hex_str.to_uppercase())
-    }
-}
+impl_fmt!(
+    Display, 10, "", false;
+    Binary, 2, "0b", false;
+    Octal, 8, "0o", false;
+    LowerHex, 16, "0x", false;
+    UpperHex, 16, "0x", true;
+);
 
 impl<const N: usize> FromStrRadix for BigUint<N> {
     type Error = ParseBigIntError;
 
     fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::Error> {
-        let mut num: BigUint<N> = BigUint::default();
+        if !(2..=36).contains(&radix) {
+            return Err(ParseBigIntError::InvalidRadix(radix));
+        }
+        BigUint::parse_digits(src, radix, false)
+    }
+}
 
-        for char in src.chars() {
-            let digit = match char.to_digit(radix) {
-                Some(digit) => digit,
-                None => return Err(ParseBigIntError::InvalidChar),
+// Panics if the radix is not in 2..=36, like the primitive integers
+impl<const N: usize> ToStrRadix for BigUint<N> {
+    #[allow(clippy::unnecessary_cast)] // a no-op with u32 limbs
+    fn to_str_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36"
+        );
+
+        let mut s = String::new();
+        let mut num = *self;
+
+        loop {
+            let (quotient, remainder) = num.div_limb(Word::from(radix));
+            s.push(
+                char::from_digit(remainder as u32, radix).expect("remainder is below the radix"),
+            );
+            num = quotient;
+
+            if quotient == BigUint::default() {
+                break;
             };
-            num = num.mul_u32(radix).add_u32(digit);
         }
 
-        Ok(num)
+        s.chars().rev().collect()
+    }
+}
+
+// Decimal unless prefixed with 0x, 0o or 0b, and underscores may separate the digits
+impl<const N: usize> FromStr for BigUint<N> {
+    type Err = ParseBigIntError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let (radix, digits) = match src.get(..2) {
+            Some("0x" | "0X") => (16, &src[2..]),
+            Some("0o" | "0O") => (8, &src[2..]),
+            Some("0b" | "0B") => (2, &src[2..]),
+            _ => (10, src),
+        };
+
+        BigUint::parse_digits(digits, radix, true)
+            .map_err(|error| error.offset(src.len() - digits.len()))
     }
 }
 
@@ -310,14 +615,14 @@ impl<const N: usize> core::ops::Sub for BigUint<N> {
 
 impl<const N: usize> core::cmp::PartialOrd for BigUint<N> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        for i in (0..N).rev() {
-            if self.limbs[i] > other.limbs[i] {
-                return Some(core::cmp::Ordering::Greater);
-            } else if self.limbs[i] < other.limbs[i] {
-                return Some(core::cmp::Ordering::Less);
-            }
-        }
-        Some(core::cmp::Ordering::Equal)
+        Some(self.cmp(other))
+    }
+}
+
+// Limbs are little endian, so they are compared from the most significant one down
+impl<const N: usize> core::cmp::Ord for BigUint<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
     }
 }
 
@@ -355,7 +660,8 @@ impl<const N: usize> core::ops::Mul for BigUint<N> {
 
 impl<const N: usize> Bit for BigUint<N> {
     fn bit(&self, index: usize) -> bool {
-        self.limbs[index / 32].bit(index % 32)
+        let bits = Word::BITS as usize;
+        self.limbs[index / bits].bit(index % bits)
     }
 }
 
@@ -373,6 +679,99 @@ impl<const N: usize> core::ops::BitAnd for BigUint<N> {
     }
 }
 
+impl<const N: usize> core::ops::BitOr for BigUint<N> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let mut limbs = self.limbs;
+
+        for (limb, rhs) in limbs.iter_mut().zip(rhs.limbs) {
+            *limb |= rhs;
+        }
+
+        BigUint::new(limbs)
+    }
+}
+
+impl<const N: usize> core::ops::BitXor for BigUint<N> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let mut limbs = self.limbs;
+
+        for (limb, rhs) in limbs.iter_mut().zip(rhs.limbs) {
+            *limb ^= rhs;
+        }
+
+        BigUint::new(limbs)
+    }
+}
+
+impl<const N: usize> core::ops::Not for BigUint<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        BigUint::new(self.limbs.map(|limb| !limb))
+    }
+}
+
+// Like the primitive integers, bits shifted past either end are dropped and shifting by the full
+// width or more panics
+impl<const N: usize> core::ops::Shl<usize> for BigUint<N> {
+    type Output = Self;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        assert!(
+            rhs < N * Word::BITS as usize,
+            "attempt to shift left with overflow"
+        );
+
+        let bits = Word::BITS as usize;
+        let (offset, shift) = (rhs / bits, (rhs % bits) as u32);
+        let mut limbs = [0; N];
+
+        for (i, limb) in limbs.iter_mut().enumerate().skip(offset) {
+            *limb = self.limbs[i - offset] << shift;
+            if shift > 0 && i > offset {
+                *limb |= self.limbs[i - offset - 1] >> (Word::BITS - shift);
+            }
+        }
+
+        BigUint::new(limbs)
+    }
+}
+
+impl<const N: usize> core::ops::Shr<usize> for BigUint<N> {
+    type Output = Self;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        assert!(
+            rhs < N * Word::BITS as usize,
+            "attempt to shift right with overflow"
+        );
+
+        let bits = Word::BITS as usize;
+        let (offset, shift) = (rhs / bits, (rhs % bits) as u32);
+        let mut limbs = [0; N];
+
+        for (i, limb) in limbs.iter_mut().take(N - offset).enumerate() {
+            *limb = self.limbs[i + offset] >> shift;
+            if shift > 0 && i + offset + 1 < N {
+                *limb |= self.limbs[i + offset + 1] << (Word::BITS - shift);
+            }
+        }
+
+        BigUint::new(limbs)
+    }
+}
+
+impl<const N: usize> SetBit for BigUint<N> {
+    fn set_bit(&mut self, index: usize, value: bool) {
+        let bits = Word::BITS as usize;
+        self.limbs[index / bits].set_bit(index % bits, value);
+    }
+}
+
 impl<const N: usize> One for BigUint<N> {
     fn one() -> Self {
         let mut limbs = [0; N];
@@ -396,82 +795,125 @@ impl<const N: usize> RemEuclid for BigUint<N> {
 
 impl<const N: usize> CheckedAdd for BigUint<N> {
     fn checked_add(&self, v: &Self) -> Option<Self> {
-        let mut w = [0; N];
-        let mut k = 0;
-
-        for j in 0..N {
-            let sum = u64::from(self.limbs[j]) + u64::from(v.limbs[j]) + k;
-            w[j] = (sum % RADIX) as u32;
-            if sum >= RADIX {
-                if j == (N - 1) {
-                    return None;
-                }
-                k = 1;
-            } else {
-                k = 0;
-            }
-        }
-
-        Some(BigUint::new(w))
+        let (sum, overflow) = self.overflowing_add(v);
+        (!overflow).then_some(sum)
     }
 }
 
 impl<const N: usize> CheckedSub for BigUint<N> {
     fn checked_sub(&self, v: &Self) -> Option<Self> {
+        let (difference, overflow) = self.overflowing_sub(v);
+        (!overflow).then_some(difference)
+    }
+}
+
+impl<const N: usize> OverflowingAdd for BigUint<N> {
+    fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
         let mut w = [0; N];
-        let mut k = 0;
+        let mut carry = false;
 
-        for j in 0..N {
-            let difference = i64::from(self.limbs[j]) - i64::from(v.limbs[j]) + k;
-            w[j] = (difference % RADIX as i64) as u32;
-            if difference < 0 {
-                if j == (N - 1) {
-                    return None;
-                }
-                k = -1;
-            } else {
-                k = 0;
-            }
+        for (limb, (&a, &b)) in w.iter_mut().zip(self.limbs.iter().zip(rhs.limbs.iter())) {
+            let (sum, c1) = a.overflowing_add(b);
+            let (sum, c2) = sum.overflowing_add(Word::from(carry));
+            *limb = sum;
+            carry = c1 || c2;
         }
 
-        Some(BigUint::new(w))
+        (BigUint::new(w), carry)
     }
 }
 
-impl<const N: usize> CheckedMul for BigUint<N> {
-    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+impl<const N: usize> OverflowingSub for BigUint<N> {
+    fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
         let mut w = [0; N];
-        let mut k = 0;
+        let mut borrow = false;
 
-        for j in 0..N {
-            k = 0;
-            if rhs.limbs[j] == 0 {
-                continue;
-            };
-            for i in 0..N {
-                if i + j >= N {
-                    if self.limbs[i] != 0 || rhs.limbs[i] != 0 {
-                        return None;
-                    }
-                } else {
-                    let t = u64::from(self.limbs[i]) * u64::from(rhs.limbs[j])
-                        + u64::from(w[i + j])
-                        + k;
-                    w[i + j] = (t % RADIX) as u32;
-                    k = t / RADIX;
-                }
-            }
+        for (limb, (&a, &b)) in w.iter_mut().zip(self.limbs.iter().zip(rhs.limbs.iter())) {
+            let (difference, b1) = a.overflowing_sub(b);
+            let (difference, b2) = difference.overflowing_sub(Word::from(borrow));
+            *limb = difference;
+            borrow = b1 || b2;
         }
 
-        if k > 0 {
-            return None;
-        }
+        (BigUint::new(w), borrow)
+    }
+}
 
-        Some(BigUint::new(w))
+impl<const N: usize> WrappingAdd for BigUint<N> {
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.overflowing_add(rhs).0
     }
 }
 
-impl<const N: usize> LeadingZeros for BigUint<N> {
+impl<const N: usize> WrappingSub for BigUint<N> {
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl<const N: usize> WrappingMul for BigUint<N> {
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.widening_mul(rhs).0
+    }
+}
+
+impl<const N: usize> WideningMul for BigUint<N> {
+    fn widening_mul(&self, rhs: &Self) -> (Self, Self) {
+        if N < KARATSUBA_THRESHOLD {
+            return self.widening_mul_schoolbook(rhs);
+        }
+
+        let product = mul::mul(&self.limbs, &rhs.limbs, KARATSUBA_THRESHOLD);
+        let (low, high) = product.split_at(N);
+
+        (
+            BigUint::from_product(low).expect("N limbs"),
+            BigUint::from_product(high).expect("N limbs"),
+        )
+    }
+}
+
+impl<const N: usize> CheckedDiv for BigUint<N> {
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        (*rhs != BigUint::zero()).then(|| *self / *rhs)
+    }
+}
+
+impl<const N: usize> CheckedRem for BigUint<N> {
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        (*rhs != BigUint::zero()).then(|| *self % *rhs)
+    }
+}
+
+// Square and multiply from the top bit of the exponent. Every intermediate value divides the
+// result, so this only panics when the result itself overflows.
+impl<const N: usize> Pow for BigUint<N> {
+    fn pow(&self, exp: u32) -> Self {
+        let mut result = BigUint::one();
+
+        for i in (0..u32::BITS - exp.leading_zeros()).rev() {
+            result = result.square();
+            if (exp >> i) & 1 == 1 {
+                result = result * *self;
+            }
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> CheckedMul for BigUint<N> {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        // the slice based routines allocate, which only pays off for the larger types
+        if N < KARATSUBA_THRESHOLD {
+            return self.checked_mul_schoolbook(rhs);
+        }
+
+        self.checked_mul_with_threshold(rhs, KARATSUBA_THRESHOLD)
+    }
+}
+
+impl<const N: usize> LeadingZeros for BigUint<N> {
     fn leading_zeros(&self) -> u32 {
         let mut zeros = 0;
 
@@ -479,7 +921,23 @@ impl<const N: usize> LeadingZeros for BigUint<N> {
             let limb_zeros = self.limbs[i].leading_zeros();
             zeros += limb_zeros;
 
-            if limb_zeros < 32 {
+            if limb_zeros < Word::BITS {
+                break;
+            }
+        }
+
+        zeros
+    }
+}
+
+impl<const N: usize> TrailingZeros for BigUint<N> {
+    fn trailing_zeros(&self) -> u32 {
+        let mut zeros = 0;
+
+        for limb in self.limbs {
+            zeros += limb.trailing_zeros();
+
+            if limb != 0 {
                 break;
             }
         }
@@ -488,18 +946,132 @@ impl<const N: usize> LeadingZeros for BigUint<N> {
     }
 }
 
-impl<const N: usize> From<u8> for BigUint<N> {
-    fn from(value: u8) -> Self {
+impl<const N: usize> CountOnes for BigUint<N> {
+    fn count_ones(&self) -> u32 {
+        self.limbs.iter().map(|limb| limb.count_ones()).sum()
+    }
+}
+
+impl<const N: usize> BitLength for BigUint<N> {
+    fn bit_length(&self) -> usize {
+        N * Word::BITS as usize - self.leading_zeros() as usize
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for BigUint<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.limbs.ct_eq(&other.limbs)
+    }
+}
+
+impl<const N: usize> ConstantTimeLess for BigUint<N> {
+    // The borrow out of self - other, which unlike `Ord` looks at every limb
+    fn ct_lt(&self, other: &Self) -> Choice {
+        let mut borrow = false;
+
+        for (&a, &b) in self.limbs.iter().zip(other.limbs.iter()) {
+            let (diff, b1) = a.overflowing_sub(b);
+            let (_, b2) = diff.overflowing_sub(borrow as Word);
+            borrow = b1 | b2;
+        }
+
+        Choice::from(borrow)
+    }
+}
+
+impl<const N: usize> ConditionallySelectable for BigUint<N> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         let mut limbs = [0; N];
-        limbs[0] = value as u32;
+
+        for (limb, (a, b)) in limbs.iter_mut().zip(a.limbs.iter().zip(b.limbs.iter())) {
+            *limb = Word::conditional_select(a, b, choice);
+        }
 
         BigUint::new(limbs)
     }
 }
 
+impl<const N: usize> core::iter::Sum for BigUint<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BigUint::zero(), |sum, n| sum + n)
+    }
+}
+
+impl<'a, const N: usize> core::iter::Sum<&'a BigUint<N>> for BigUint<N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const N: usize> core::iter::Product for BigUint<N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BigUint::one(), |product, n| product * n)
+    }
+}
+
+impl<'a, const N: usize> core::iter::Product<&'a BigUint<N>> for BigUint<N> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+// A type too narrow for the primitive fails to compile rather than panicking on every value
+macro_rules! impl_from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> From<$t> for BigUint<N> {
+                fn from(value: $t) -> Self {
+                    const {
+                        assert!(
+                            <$t>::BITS <= N as u32 * Word::BITS,
+                            "type is too narrow for the primitive"
+                        )
+                    };
+
+                    BigUint::from_le_slice(&value.to_le_bytes()).expect("value fits")
+                }
+            }
+
+            impl<const N: usize> TryFrom<BigUint<N>> for $t {
+                type Error = TryFromBigIntError;
+
+                #[allow(clippy::unnecessary_cast)] // a no-op for u128
+                fn try_from(value: BigUint<N>) -> Result<Self, Self::Error> {
+                    if value.bit_length() > <$t>::BITS as usize {
+                        return Err(TryFromBigIntError);
+                    }
+
+                    let low = value
+                        .limbs
+                        .iter()
+                        .take(u128::BITS.div_ceil(Word::BITS) as usize)
+                        .rev()
+                        .fold(0u128, |acc, &limb| (acc << Word::BITS) | limb as u128);
+
+                    Ok(low as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(u8, u16, u32, u64, u128);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::{format, vec::Vec};
+    use types::{U2048, U256, U4096};
+
+    // Test values written as 32-bit words, least significant first, so they stand for the same
+    // number with either limb width
+    macro_rules! words {
+        ($($word:expr),* $(,)?) => {
+            BigUint::<{ ([$($word as u32),*].len() * 32).div_ceil(Word::BITS as usize) }>::from_words(
+                [$($word),*],
+            )
+        };
+    }
 
     #[test]
     fn add() {
@@ -511,10 +1083,10 @@ mod tests {
 
     #[test]
     fn add_with_carry() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX, 0]);
-        let b = BigUint::new([u32::MAX, u32::MAX, u32::MAX, 0]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX, 0]);
+        let b = BigUint::new([Word::MAX, Word::MAX, Word::MAX, 0]);
         let result = a + b;
-        assert_eq!(result.limbs, [u32::MAX - 1, u32::MAX, u32::MAX, 1]); // Check for carry propagation
+        assert_eq!(result.limbs, [Word::MAX - 1, Word::MAX, Word::MAX, 1]); // Check for carry propagation
     }
 
     #[test]
@@ -527,7 +1099,7 @@ mod tests {
 
     #[test]
     fn add_with_carry_propagation() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX, 0]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX, 0]);
         let b = BigUint::new([1, 0, 0, 0]);
         let result = a + b;
         assert_eq!(result.limbs, [0, 0, 0, 1]);
@@ -536,14 +1108,14 @@ mod tests {
     #[test]
     #[should_panic]
     fn add_panic_on_overflow() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX]);
         let b = BigUint::new([1, 0, 0]);
         let _ = a + b;
     }
 
     #[test]
     fn mul_with_0() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX, u32::MAX]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX, Word::MAX]);
         let b = BigUint::new([0, 0, 0, 0]);
         let result = a * b;
         assert_eq!(result.limbs, [0, 0, 0, 0]);
@@ -551,18 +1123,18 @@ mod tests {
 
     #[test]
     fn mul_with_1() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX, u32::MAX]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX, Word::MAX]);
         let b = BigUint::new([1, 0, 0, 0]);
         let result = a * b;
-        assert_eq!(result.limbs, [u32::MAX, u32::MAX, u32::MAX, u32::MAX]);
+        assert_eq!(result.limbs, [Word::MAX, Word::MAX, Word::MAX, Word::MAX]);
     }
 
     #[test]
     fn mul_double() {
-        let a = BigUint::new([u32::MAX, u32::MAX, 0]);
+        let a = BigUint::new([Word::MAX, Word::MAX, 0]);
         let b = BigUint::new([2, 0, 0]);
         let result = a * b;
-        assert_eq!(result.limbs, [u32::MAX - 1, u32::MAX, 1]);
+        assert_eq!(result.limbs, [Word::MAX - 1, Word::MAX, 1]);
     }
 
     #[test]
@@ -575,18 +1147,18 @@ mod tests {
 
     #[test]
     fn mul_square() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX, 0, 0, 0]);
-        let b = BigUint::new([u32::MAX, u32::MAX, u32::MAX, 0, 0, 0]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX, 0, 0, 0]);
+        let b = BigUint::new([Word::MAX, Word::MAX, Word::MAX, 0, 0, 0]);
         let result = a * b;
-        assert_eq!(result.limbs, [1, 0, 0, u32::MAX - 1, u32::MAX, u32::MAX]);
+        assert_eq!(result.limbs, [1, 0, 0, Word::MAX - 1, Word::MAX, Word::MAX]);
     }
 
     #[test]
     fn mul_triple() {
-        let a = BigUint::new([u32::MAX / 3, u32::MAX / 3, u32::MAX / 3]);
+        let a = BigUint::new([Word::MAX / 3, Word::MAX / 3, Word::MAX / 3]);
         let b = BigUint::new([3, 0, 0]);
         let result = a * b;
-        assert_eq!(result.limbs, [u32::MAX, u32::MAX, u32::MAX]);
+        assert_eq!(result.limbs, [Word::MAX, Word::MAX, Word::MAX]);
     }
 
     #[test]
@@ -599,73 +1171,173 @@ mod tests {
 
     #[test]
     fn mul_max_allowable_mul() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX, 0]);
+        let a = BigUint::new([Word::MAX, Word::MAX, Word::MAX, 0]);
         let b = BigUint::new([0, 1, 0, 0]);
         let result = a * b;
-        assert_eq!(result.limbs, [0, u32::MAX, u32::MAX, u32::MAX]);
+        assert_eq!(result.limbs, [0, Word::MAX, Word::MAX, Word::MAX]);
+    }
+
+    // xorshift, so the tests cover many limb patterns without extra dependencies
+    pub(crate) fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn random<const N: usize>(seed: &mut u64, digits: usize) -> BigUint<N> {
+        let mut limbs = [0; N];
+        for limb in limbs.iter_mut().take(digits) {
+            *limb = xorshift(seed) as Word;
+        }
+
+        BigUint::new(limbs)
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook_u2048() {
+        let mut seed = 0x853c_49e6_748f_ea9b;
+        let n = U2048::LIMBS;
+
+        for (a_digits, b_digits) in [
+            (n / 2, n / 2),
+            (3 * n / 8, 5 * n / 8),
+            (n / 2 - 2, n / 2 - 7),
+            (n, 0),
+            (1, n - 1),
+        ] {
+            let a: U2048 = random(&mut seed, a_digits);
+            let b: U2048 = random(&mut seed, b_digits);
+
+            assert_eq!(a.checked_mul_schoolbook(&b), a.checked_mul(&b));
+            assert_eq!(a.checked_mul_schoolbook(&a), a.checked_square());
+        }
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook_u4096() {
+        let mut seed = 0xda3e_39cb_94b9_5bdb;
+        let n = U4096::LIMBS;
+
+        for (a_digits, b_digits) in [
+            (n / 2, n / 2),
+            (n / 2, n / 2 - 4),
+            (3 * n / 4 + 4, n / 4 - 4),
+            (n / 2 + 1, n / 2),
+            (n, n),
+        ] {
+            let a: U4096 = random(&mut seed, a_digits);
+            let b: U4096 = random(&mut seed, b_digits);
+
+            assert_eq!(a.checked_mul_schoolbook(&b), a.checked_mul(&b));
+            assert_eq!(a.checked_mul_schoolbook(&a), a.checked_square());
+        }
+    }
+
+    #[test]
+    fn mul_with_any_threshold() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+        let n = U2048::LIMBS;
+
+        for threshold in [4, 5, 8, n / 2, n] {
+            let a: U2048 = random(&mut seed, n / 2);
+            let b: U2048 = random(&mut seed, n / 2 - 3);
+
+            assert_eq!(
+                a.checked_mul_schoolbook(&b),
+                a.checked_mul_with_threshold(&b, threshold),
+                "threshold {}",
+                threshold
+            );
+            assert_eq!(
+                a.checked_mul_schoolbook(&a),
+                a.checked_square_with_threshold(threshold),
+                "threshold {}",
+                threshold
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Karatsuba threshold must be at least 4")]
+    fn mul_with_too_small_threshold() {
+        let a = U2048::one();
+        let _ = a.checked_mul_with_threshold(&(a + a), 3);
+    }
+
+    #[test]
+    fn square_overflow() {
+        let a = U2048::new([Word::MAX; U2048::LIMBS]);
+        let mut half = [0; U2048::LIMBS];
+        half[..U2048::LIMBS / 2].fill(Word::MAX);
+        let b = U2048::new(half);
+
+        assert_eq!(None, a.checked_square());
+        assert_eq!(None, a.checked_mul(&b));
+        assert_eq!(b.checked_mul_schoolbook(&b), Some(b.square()));
     }
 
     #[test]
-    fn div_u32_by_1() {
-        let a = BigUint::new([12345, 67890, 54321]);
-        let (quotient, remainder) = a.div_u32(1);
-        assert_eq!(quotient.limbs, [12345, 67890, 54321]);
+    fn div_limb_by_1() {
+        let a = words!(12345, 67890, 54321);
+        let (quotient, remainder) = a.div_limb(1);
+        assert_eq!(quotient, a);
         assert_eq!(remainder, 0);
     }
 
     #[test]
-    fn div_u32_by_larger_number() {
+    fn div_limb_by_larger_number() {
         let a: BigUint<4> = BigUint::from_str_radix("1002045585119561883070521", 10).unwrap();
-        let (quotient, remainder) = a.div_u32(u32::MAX);
+        let (quotient, remainder) = a.div_limb(0xffff_ffff);
         assert_eq!(quotient.to_str_radix(10), "233306918608227");
         assert_eq!(remainder, 134556);
     }
 
     #[test]
-    fn div_u32_by_smaller_number() {
-        let a = BigUint::new([123456789, 987654321]);
-        let (quotient, remainder) = a.div_u32(12345);
+    fn div_limb_by_smaller_number() {
+        let a = words!(123456789, 987654321);
+        let (quotient, remainder) = a.div_limb(12345);
         assert_eq!(quotient.to_str_radix(10), "343616282589837");
         assert_eq!(remainder, 5040);
     }
 
     #[test]
-    fn div_u32_by_power_of_2() {
-        let a = BigUint::new([123456789, 987654321]);
-        let (quotient, remainder) = a.div_u32(8);
+    fn div_limb_by_power_of_2() {
+        let a = words!(123456789, 987654321);
+        let (quotient, remainder) = a.div_limb(8);
         assert_eq!(quotient.to_str_radix(10), "530242876071442850");
         assert_eq!(remainder, 5);
     }
 
     #[test]
-    fn div_u32_by_u32_max() {
-        let a = BigUint::new([u32::MAX, u32::MAX, u32::MAX]);
-        let (quotient, remainder) = a.div_u32(u32::MAX);
+    fn div_limb_by_u32_max() {
+        let a = words!(u32::MAX, u32::MAX, u32::MAX);
+        let (quotient, remainder) = a.div_limb(0xffff_ffff);
         assert_eq!(quotient.to_str_radix(10), "18446744078004518913");
         assert_eq!(remainder, 0);
     }
 
     #[test]
-    fn div_u32_large_number() {
-        let a = BigUint::new([0, 0, u32::MAX]);
-        let (quotient, remainder) = a.div_u32(2);
+    fn div_limb_large_number() {
+        let a = words!(0, 0, u32::MAX);
+        let (quotient, remainder) = a.div_limb(2);
         assert_eq!(quotient.to_str_radix(10), "39614081247908796759917199360");
         assert_eq!(remainder, 0);
     }
 
     #[test]
-    fn div_u32_with_remainder() {
-        let a = BigUint::new([1, 2, 3]);
-        let (quotient, remainder) = a.div_u32(2);
-        assert_eq!(quotient.limbs, [0, 2147483649, 1]);
+    fn div_limb_with_remainder() {
+        let a = words!(1, 2, 3);
+        let (quotient, remainder) = a.div_limb(2);
+        assert_eq!(quotient, words!(0, 2147483649, 1));
         assert_eq!(remainder, 1);
     }
 
     #[test]
-    fn div_u32_complex() {
-        let a = BigUint::new([1, 1, 1, 1]);
-        let (quotient, remainder) = a.div_u32(2);
-        assert_eq!(quotient.limbs, [2147483648, 2147483648, 2147483648, 0]);
+    fn div_limb_complex() {
+        let a = words!(1, 1, 1, 1);
+        let (quotient, remainder) = a.div_limb(2);
+        assert_eq!(quotient, words!(2147483648, 2147483648, 2147483648, 0));
         assert_eq!(remainder, 1);
     }
 
@@ -687,19 +1359,197 @@ mod tests {
         assert_eq!(src, from);
     }
 
+    #[test]
+    fn from_str_radix_errors() {
+        assert_eq!(Err(ParseBigIntError::Empty), U256::from_str_radix("", 16));
+        assert_eq!(
+            Err(ParseBigIntError::InvalidRadix(37)),
+            U256::from_str_radix("12", 37)
+        );
+        assert_eq!(
+            Err(ParseBigIntError::InvalidRadix(1)),
+            U256::from_str_radix("0", 1)
+        );
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 3 }),
+            U256::from_str_radix("12ag", 16)
+        );
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 1 }),
+            U256::from_str_radix("1a", 10)
+        );
+    }
+
+    #[test]
+    fn from_str_radix_overflow() {
+        let max = "f".repeat(64);
+        assert_eq!(
+            U256::from_words([u32::MAX; 8]),
+            U256::from_str_radix(&max, 16).unwrap()
+        );
+
+        let too_long = "1".repeat(65);
+        assert_eq!(
+            Err(ParseBigIntError::Overflow { position: 64 }),
+            U256::from_str_radix(&too_long, 16)
+        );
+
+        // 2^256 only overflows on the final addition
+        let two_pow_256 =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(
+            Err(ParseBigIntError::Overflow { position: 77 }),
+            U256::from_str_radix(two_pow_256, 10)
+        );
+    }
+
+    #[test]
+    fn formatting_flags() {
+        let n = words!(0xdead_beef);
+
+        assert_eq!("3735928559", format!("{}", n));
+        assert_eq!("deadbeef", format!("{:x}", n));
+        assert_eq!("0xDEADBEEF", format!("{:#X}", n));
+        assert_eq!("0o33653337357", format!("{:#o}", n));
+        assert_eq!("11011110101011011011111011101111", format!("{:b}", n));
+        assert_eq!("0x00deadbeef", format!("{:#012x}", n));
+        assert_eq!("  3735928559", format!("{:>12}", n));
+        assert_eq!("+3735928559*", format!("{:*<+12}", n));
+        assert_eq!("0", format!("{}", U256::zero()));
+        assert_eq!("0b0000", format!("{:#06b}", U256::zero()));
+    }
+
+    #[test]
+    fn from_str_prefixes_and_underscores() {
+        let n = words!(0xdead_beef);
+
+        assert_eq!(Ok(n), "3735928559".parse());
+        assert_eq!(Ok(n), "3_735_928_559".parse());
+        assert_eq!(Ok(n), "0xdead_beef".parse());
+        assert_eq!(Ok(n), "0XDEADBEEF".parse());
+        assert_eq!(Ok(n), "0o33653337357".parse());
+        assert_eq!(Ok(n), "0b1101_1110_1010_1101_1011_1110_1110_1111".parse());
+        assert_eq!(Ok(n), format!("{:#x}", n).parse());
+        assert_eq!(Ok(n), format!("{:#b}", n).parse());
+        assert_eq!(Ok(U256::zero()), "0".parse());
+    }
+
+    #[test]
+    fn from_str_errors() {
+        assert_eq!(Err(ParseBigIntError::Empty), "".parse::<U256>());
+        assert_eq!(Err(ParseBigIntError::Empty), "0x".parse::<U256>());
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 2 }),
+            "0x_1".parse::<U256>()
+        );
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 0 }),
+            "_1".parse::<U256>()
+        );
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 4 }),
+            "0b10201".parse::<U256>()
+        );
+        assert_eq!(
+            Err(ParseBigIntError::InvalidChar { position: 2 }),
+            "12ab".parse::<U256>()
+        );
+        assert_eq!(
+            Err(ParseBigIntError::Overflow { position: 66 }),
+            format!("0x1{}", "0".repeat(64)).parse::<U256>()
+        );
+    }
+
+    #[test]
+    fn str_radix_matches_primitives() {
+        for n in [0, 1, 35, 36, 0xbeef, u64::MAX as u128, u128::MAX] {
+            for radix in [2, 8, 10, 16, 36] {
+                let digits = n.to_str_radix(radix);
+
+                assert_eq!(
+                    digits,
+                    U256::from(n).to_str_radix(radix),
+                    "{} in base {}",
+                    n,
+                    radix
+                );
+                assert_eq!(Ok(U256::from(n)), U256::from_str_radix(&digits, radix));
+                assert_eq!(
+                    Ok(n),
+                    <u128 as FromStrRadix>::from_str_radix(&digits, radix)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2..=36")]
+    fn to_str_radix_invalid_radix() {
+        words!(1).to_str_radix(37);
+    }
+
+    #[test]
+    fn from_be_hex_const() {
+        const A: U256 = U256::from_be_hex_const("0123456789abcdefABCDEF");
+
+        assert_eq!(
+            U256::from_str_radix("123456789abcdefabcdef", 16).unwrap(),
+            A
+        );
+        assert_eq!(
+            U256::from_words([u32::MAX; 8]),
+            U256::from_be_hex_const(&"f".repeat(64))
+        );
+        assert_eq!(
+            words!(1, 0, 0),
+            BigUint::from_be_hex_const("00000000 00000000 00000000 00000000 00000001")
+        );
+        assert_eq!(
+            words!(0x9abcdef0, 0x12345678),
+            BigUint::from_be_hex_const("12345678 9abcdef0")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex digit")]
+    fn from_be_hex_const_invalid_digit() {
+        U256::from_be_hex_const("12g4");
+    }
+
+    #[test]
+    #[should_panic(expected = "empty hex string")]
+    fn from_be_hex_const_empty() {
+        U256::from_be_hex_const(" ");
+    }
+
+    #[test]
+    #[should_panic(expected = "hex value does not fit")]
+    fn from_be_hex_const_overflow() {
+        U256::from_be_hex_const(&"1".repeat(65));
+    }
+
+    #[test]
+    fn checked_from_words() {
+        assert_eq!(
+            Some(U256::from_words([1, 2])),
+            U256::checked_from_words([1, 2, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(None, U256::checked_from_words([1, 2, 0, 0, 0, 0, 0, 0, 3]));
+    }
+
     #[test]
     #[should_panic]
     fn sub_overflow() {
-        let a = BigUint::new([0, u32::MAX, u32::MAX]);
-        let b = BigUint::new([1, u32::MAX, u32::MAX]);
+        let a = BigUint::new([0, Word::MAX, Word::MAX]);
+        let b = BigUint::new([1, Word::MAX, Word::MAX]);
         let result = a - b;
         assert_eq!(result, BigUint::default());
     }
 
     #[test]
     fn sub_to_zero() {
-        let a = BigUint::new([1, u32::MAX, u32::MAX]);
-        let b = BigUint::new([1, u32::MAX, u32::MAX]);
+        let a = BigUint::new([1, Word::MAX, Word::MAX]);
+        let b = BigUint::new([1, Word::MAX, Word::MAX]);
         let result = a - b;
         assert_eq!(result, BigUint::default());
     }
@@ -712,7 +1562,7 @@ mod tests {
         assert_eq!(
             result,
             BigUint {
-                limbs: [u32::MAX, u32::MAX, 0]
+                limbs: [Word::MAX, Word::MAX, 0]
             }
         );
     }
@@ -725,11 +1575,324 @@ mod tests {
         assert_eq!(
             result,
             BigUint {
-                limbs: [u32::MAX, u32::MAX - 1, 0]
+                limbs: [Word::MAX, Word::MAX - 1, 0]
             }
         );
     }
 
+    #[test]
+    fn overflowing_and_wrapping_add_sub() {
+        let max = BigUint::new([Word::MAX; 3]);
+        let one = BigUint::<3>::one();
+
+        assert_eq!((BigUint::zero(), true), max.overflowing_add(&one));
+        assert_eq!((max, true), BigUint::zero().overflowing_sub(&one));
+        assert_eq!((max, false), (max - one).overflowing_add(&one));
+        assert_eq!(
+            BigUint::new([Word::MAX - 1, Word::MAX, Word::MAX]),
+            max.wrapping_add(&max)
+        );
+        assert_eq!(BigUint::new([2, 0, 0]), one.wrapping_sub(&max));
+        assert_eq!(None, max.checked_add(&one));
+        assert_eq!(None, one.checked_sub(&max));
+    }
+
+    #[test]
+    fn widening_and_wrapping_mul() {
+        let max = BigUint::new([Word::MAX; 3]);
+
+        // (2^k - 1)^2 = 2^2k - 2^(k + 1) + 1
+        assert_eq!(
+            (
+                BigUint::one(),
+                BigUint::new([Word::MAX - 1, Word::MAX, Word::MAX])
+            ),
+            max.widening_mul(&max)
+        );
+        assert_eq!(BigUint::one(), max.wrapping_mul(&max));
+        assert_eq!(
+            (BigUint::zero(), BigUint::zero()),
+            max.widening_mul(&BigUint::zero())
+        );
+
+        let a = words!(0x89ab_cdef, 0x0123_4567);
+        let b = words!(0xfedc_ba98, 0x7654_3210);
+        let (low, high) = a.widening_mul(&b);
+        assert_eq!(words!(0xad05_ebe8, 0x9252_c268), low);
+        assert_eq!(words!(0x7652_e6a8, 0x0086_a1c9), high);
+    }
+
+    #[test]
+    fn widening_mul_matches_karatsuba() {
+        let mut seed = 0x6a09_e667_f3bc_c908;
+
+        for digits in [1, U4096::LIMBS / 2, U4096::LIMBS] {
+            let a: U4096 = random(&mut seed, digits);
+            let b: U4096 = random(&mut seed, U4096::LIMBS);
+
+            assert_eq!(a.widening_mul_schoolbook(&b), a.widening_mul(&b));
+        }
+    }
+
+    #[test]
+    fn checked_div_and_rem() {
+        let a = words!(100, 0);
+
+        assert_eq!(Some(words!(14, 0)), a.checked_div(&words!(7, 0)));
+        assert_eq!(Some(words!(2, 0)), a.checked_rem(&words!(7, 0)));
+        assert_eq!(None, a.checked_div(&BigUint::zero()));
+        assert_eq!(None, a.checked_rem(&BigUint::zero()));
+    }
+
+    #[test]
+    fn pow() {
+        let three = U256::from(3u8);
+
+        assert_eq!(U256::one(), three.pow(0));
+        assert_eq!(three, three.pow(1));
+        assert_eq!(U256::from(3u128.pow(80)), three.pow(80));
+        assert_eq!(U256::one() << 255, U256::from(2u8).pow(255));
+        assert_eq!(U256::zero(), U256::zero().pow(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "integer overflow")]
+    fn pow_panic_on_overflow() {
+        U256::from(2u8).pow(256);
+    }
+
+    #[test]
+    fn ct_eq() {
+        let a = BigUint::new([1, 2, 3]);
+
+        assert!(bool::from(a.ct_eq(&BigUint::new([1, 2, 3]))));
+        assert!(!bool::from(a.ct_eq(&BigUint::new([1, 2, 4]))));
+        assert!(!bool::from(a.ct_eq(&BigUint::new([0, 2, 3]))));
+        assert!(bool::from(a.ct_ne(&BigUint::new([0, 2, 3]))));
+        assert!(bool::from(BigUint::<3>::zero().ct_eq(&BigUint::default())));
+    }
+
+    #[test]
+    fn ct_lt() {
+        let values = [
+            BigUint::new([0, 0, 0]),
+            BigUint::new([1, 0, 0]),
+            BigUint::new([Word::MAX, 0, 0]),
+            BigUint::new([0, 1, 0]),
+            BigUint::new([Word::MAX, Word::MAX, 0]),
+            BigUint::new([0, 0, 1]),
+            BigUint::new([1, 0, 1]),
+            BigUint::new([Word::MAX, Word::MAX, Word::MAX]),
+        ];
+
+        for a in &values {
+            for b in &values {
+                assert_eq!(a < b, bool::from(a.ct_lt(b)), "{:?} < {:?}", a, b);
+                assert_eq!(a > b, bool::from(a.ct_gt(b)), "{:?} > {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn conditional_select() {
+        let a = BigUint::new([1, 2, 3]);
+        let b = BigUint::new([Word::MAX, 0, 7]);
+
+        assert_eq!(a, BigUint::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, BigUint::conditional_select(&a, &b, Choice::from(1)));
+
+        let (mut x, mut y) = (a, b);
+        BigUint::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((b, a), (x, y));
+        BigUint::conditional_swap(&mut x, &mut y, Choice::from(0));
+        assert_eq!((b, a), (x, y));
+    }
+
+    #[test]
+    fn ordering_and_hashing() {
+        extern crate std;
+        use std::collections::{BTreeMap, HashSet};
+
+        let mut values = Vec::from([
+            BigUint::new([0, 0, 1]),
+            BigUint::new([Word::MAX, 0, 0]),
+            BigUint::new([0, 1, 0]),
+            BigUint::new([5, 0, 0]),
+            BigUint::new([0, 1, 0]),
+        ]);
+
+        assert_eq!(Some(&BigUint::new([0, 0, 1])), values.iter().max());
+        assert_eq!(Some(&BigUint::new([5, 0, 0])), values.iter().min());
+
+        values.sort();
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let distinct: HashSet<_> = values.iter().collect();
+        assert_eq!(4, distinct.len());
+
+        let counts = values.iter().fold(BTreeMap::new(), |mut counts, n| {
+            *counts.entry(*n).or_insert(0) += 1;
+            counts
+        });
+        assert_eq!(Some(&2), counts.get(&BigUint::new([0, 1, 0])));
+        assert_eq!(Some(&BigUint::new([5, 0, 0])), counts.keys().next());
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values: Vec<U256> = (1..=20u8).map(BigUint::from).collect();
+
+        assert_eq!(BigUint::from(210u8), values.iter().sum());
+        assert_eq!(
+            BigUint::from(2_432_902_008_176_640_000u64),
+            values.iter().product()
+        );
+        assert_eq!(U256::zero(), core::iter::empty::<U256>().sum());
+        assert_eq!(U256::one(), core::iter::empty::<U256>().product());
+    }
+
+    #[test]
+    #[should_panic(expected = "integer overflow")]
+    fn sum_panic_on_overflow() {
+        let _: U256 = [U256::from(1u8), !U256::zero()].into_iter().sum();
+    }
+
+    #[test]
+    fn primitive_conversions() {
+        let n = U256::from(u128::MAX - 1);
+        assert_eq!(
+            U256::from_words([u32::MAX - 1, u32::MAX, u32::MAX, u32::MAX]),
+            n
+        );
+
+        assert_eq!(Ok(u128::MAX - 1), u128::try_from(n));
+        assert_eq!(Err(TryFromBigIntError), u64::try_from(n));
+        assert_eq!(Err(TryFromBigIntError), u128::try_from(n << 1));
+
+        for value in [0u64, 1, 0xff, 0x1234_5678, u64::MAX] {
+            let n = U256::from(value);
+            assert_eq!(Ok(value), u64::try_from(n));
+            assert_eq!(u32::try_from(value).ok(), u32::try_from(n).ok());
+            assert_eq!(u16::try_from(value).ok(), u16::try_from(n).ok());
+            assert_eq!(u8::try_from(value).ok(), u8::try_from(n).ok());
+        }
+
+        assert_eq!(Ok(0xbeef), u16::try_from(BigUint::<1>::from(0xbeefu16)));
+    }
+
+    #[test]
+    fn shl_across_limbs() {
+        let a = words!(0x80000001, 0, 0, 0);
+
+        assert_eq!(a, a << 0);
+        assert_eq!(words!(0x00000002, 1, 0, 0), a << 1);
+        assert_eq!(words!(0, 0x80000001, 0, 0), a << 32);
+        assert_eq!(words!(0, 0, 0x00000010, 8), a << 68);
+        assert_eq!(words!(0, 0, 0, 0x80000000), a << 127);
+    }
+
+    #[test]
+    fn shr_across_limbs() {
+        let a = words!(0, 0, 0, 0x80000001);
+
+        assert_eq!(a, a >> 0);
+        assert_eq!(words!(0, 0, 0x80000000, 0x40000000), a >> 1);
+        assert_eq!(words!(0, 0, 0x80000001, 0), a >> 32);
+        assert_eq!(words!(0x10000000, 0x08000000, 0, 0), a >> 68);
+        assert_eq!(words!(1, 0, 0, 0), a >> 127);
+    }
+
+    #[test]
+    fn shifts_match_mul_and_div() {
+        let a = words!(0x12345678, 0x9abcdef0, 0x0fedcba9, 0);
+
+        for shift in [1, 5, 31, 32, 33, 63, 64, 65, 95] {
+            let mut power = words!(0, 0, 0, 0);
+            power.set_bit(shift, true);
+
+            assert_eq!(a.div_rem(power).0, a >> shift, "a >> {}", shift);
+            if shift < 32 {
+                assert_eq!(a * power, a << shift, "a << {}", shift);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn shl_panic_on_overflow() {
+        let _ = words!(1, 0, 0, 0) << 128;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift right with overflow")]
+    fn shr_panic_on_overflow() {
+        let _ = words!(1, 0, 0, 0) >> 128;
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        let a = words!(0xff00ff00, 0x0000ffff);
+        let b = words!(0x0ff00ff0, 0xffff0000);
+
+        assert_eq!(words!(0x0f000f00, 0), a & b);
+        assert_eq!(words!(0xfff0fff0, 0xffffffff), a | b);
+        assert_eq!(words!(0xf0f0f0f0, 0xffffffff), a ^ b);
+        assert_eq!(words!(0x00ff00ff, 0xffff0000), !a);
+        assert_eq!(BigUint::zero(), a ^ a);
+    }
+
+    #[test]
+    fn set_bit() {
+        let mut a = words!(0, 0, 0, 0);
+
+        a.set_bit(0, true);
+        a.set_bit(70, true);
+        a.set_bit(127, true);
+        assert_eq!(words!(1, 0, 0x40, 0x80000000), a);
+
+        a.set_bit(70, false);
+        assert_eq!(words!(1, 0, 0, 0x80000000), a);
+    }
+
+    #[test]
+    fn bit_queries() {
+        let a = words!(0, 0x00000100, 0x00000003, 0);
+
+        assert_eq!(40, a.trailing_zeros());
+        assert_eq!(3, a.count_ones());
+        assert_eq!(66, a.bit_length());
+
+        let zero = words!(0, 0, 0, 0);
+        assert_eq!(128, zero.trailing_zeros());
+        assert_eq!(0, zero.count_ones());
+        assert_eq!(0, zero.bit_length());
+
+        let max = !zero;
+        assert_eq!(0, max.trailing_zeros());
+        assert_eq!(128, max.count_ones());
+        assert_eq!(128, max.bit_length());
+    }
+
+    #[test]
+    fn bits_iterator() {
+        let a = words!(0b1101, 0);
+
+        assert_eq!([true, false, true, true], a.bits().collect::<Vec<_>>()[..]);
+        assert_eq!(
+            [true, true, false, true],
+            a.bits().rev().collect::<Vec<_>>()[..]
+        );
+        assert_eq!(0, words!(0, 0).bits().len());
+
+        let b = words!(0x9abcdef0, 0x12345678, 0x0fedcba9, 1);
+        let mut rebuilt = words!(0, 0, 0, 0);
+        for bit in b.bits().rev() {
+            rebuilt = rebuilt << 1;
+            rebuilt.set_bit(0, bit);
+        }
+        assert_eq!(b, rebuilt);
+    }
+
     const TEST: [u32; 303] = [
         // m, n, u...,          v...,          cq...,  cr....
         1, 1, 3, 0, 1, 1, // Error, divide by 0.
@@ -775,7 +1938,7 @@ mod tests {
             let n = TEST[j] as usize;
             j += 1;
 
-            let mut dividend = [0; 7];
+            let mut dividend = [0; 8];
             let mut last = 0;
             let bound = j + m;
 
@@ -785,7 +1948,7 @@ mod tests {
                 j += 1;
             }
 
-            let mut divisor = [0; 7];
+            let mut divisor = [0; 8];
             let mut last = 0;
             let bound = j + n;
 
@@ -795,7 +1958,7 @@ mod tests {
                 j += 1;
             }
 
-            let mut quotient = [0; 7];
+            let mut quotient = [0; 8];
             let mut last = 0;
             let bound = j + core::cmp::max(m - n + 1, 1);
 
@@ -805,7 +1968,7 @@ mod tests {
                 j += 1;
             }
 
-            let mut remainder = [0; 7];
+            let mut remainder = [0; 8];
             let mut last = 0;
             let bound = j + n;
 
@@ -815,10 +1978,10 @@ mod tests {
                 j += 1;
             }
 
-            let big_dividend = BigUint::new(dividend);
-            let big_divisor = BigUint::new(divisor);
-            let big_quotient = BigUint::new(quotient);
-            let big_remainder = BigUint::new(remainder);
+            let big_dividend = U256::from_words(dividend);
+            let big_divisor = U256::from_words(divisor);
+            let big_quotient = U256::from_words(quotient);
+            let big_remainder = U256::from_words(remainder);
             let (quotient, remainder) = big_dividend.div_rem(big_divisor);
 
             assert_eq!(
@@ -839,31 +2002,31 @@ mod tests {
     #[test]
     fn div_nonzero_by_itself() {
         {
-            let a = BigUint::new([3]);
-            let b = BigUint::new([3]);
-            let expected_quotient = BigUint::new([1]);
-            let expected_remainder = BigUint::new([0]);
+            let a = words!(3);
+            let b = words!(3);
+            let expected_quotient = words!(1);
+            let expected_remainder = words!(0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([u32::MAX]);
-            let b = BigUint::new([u32::MAX]);
-            let expected_quotient = BigUint::new([1]);
-            let expected_remainder = BigUint::new([0]);
+            let a = words!(u32::MAX);
+            let b = words!(u32::MAX);
+            let expected_quotient = words!(1);
+            let expected_remainder = words!(0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x0000ffff, 0x0000ffff]);
-            let b = BigUint::new([0x0000ffff, 0x0000ffff]);
-            let expected_quotient = BigUint::new([1, 0]);
-            let expected_remainder = BigUint::new([0, 0]);
+            let a = words!(0x0000ffff, 0x0000ffff);
+            let b = words!(0x0000ffff, 0x0000ffff);
+            let expected_quotient = words!(1, 0);
+            let expected_remainder = words!(0, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x0000789a, 0x0000bcde]);
-            let b = BigUint::new([0x0000789a, 0x0000bcde]);
-            let expected_quotient = BigUint::new([1, 0]);
-            let expected_remainder = BigUint::new([0, 0]);
+            let a = words!(0x0000789a, 0x0000bcde);
+            let b = words!(0x0000789a, 0x0000bcde);
+            let expected_quotient = words!(1, 0);
+            let expected_remainder = words!(0, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
@@ -871,44 +2034,44 @@ mod tests {
     #[test]
     fn div_nonzero_by_1() {
         {
-            let a = BigUint::new([u32::MAX]);
-            let b = BigUint::new([1]);
-            let expected_quotient = BigUint::new([u32::MAX]);
-            let expected_remainder = BigUint::new([0]);
+            let a = words!(u32::MAX);
+            let b = words!(1);
+            let expected_quotient = words!(u32::MAX);
+            let expected_remainder = words!(0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([u32::MAX, u32::MAX]);
-            let b = BigUint::new([1, 0]);
-            let expected_quotient = BigUint::new([u32::MAX, u32::MAX]);
-            let expected_remainder = BigUint::new([0, 0]);
+            let a = words!(u32::MAX, u32::MAX);
+            let b = words!(1, 0);
+            let expected_quotient = words!(u32::MAX, u32::MAX);
+            let expected_remainder = words!(0, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
 
     #[test]
     fn div_dividend_repeats_divisor() {
-        let a = BigUint::new([u32::MAX, u32::MAX]);
-        let b = BigUint::new([u32::MAX, 0]);
-        let expected_quotient = BigUint::new([1, 1]);
-        let expected_remainder = BigUint::new([0, 0]);
+        let a = words!(u32::MAX, u32::MAX);
+        let b = words!(u32::MAX, 0);
+        let expected_quotient = words!(1, 1);
+        let expected_remainder = words!(0, 0);
         assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
     }
 
     #[test]
     fn div_divisor_exceeds_dividend() {
         {
-            let a = BigUint::new([3]);
-            let b = BigUint::new([4]);
-            let expected_quotient = BigUint::new([0]);
-            let expected_remainder = BigUint::new([3]);
+            let a = words!(3);
+            let b = words!(4);
+            let expected_quotient = words!(0);
+            let expected_remainder = words!(3);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x00007899, 0x0000bcde]);
-            let b = BigUint::new([0x0000789a, 0x0000bcde]);
-            let expected_quotient = BigUint::new([0, 0]);
-            let expected_remainder = BigUint::new([0x00007899, 0x0000bcde]);
+            let a = words!(0x00007899, 0x0000bcde);
+            let b = words!(0x0000789a, 0x0000bcde);
+            let expected_quotient = words!(0, 0);
+            let expected_remainder = words!(0x00007899, 0x0000bcde);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
@@ -916,94 +2079,94 @@ mod tests {
     #[test]
     fn div_common_cases() {
         {
-            let a = BigUint::new([3]);
-            let b = BigUint::new([2]);
-            let expected_quotient = BigUint::new([1]);
-            let expected_remainder = BigUint::new([1]);
+            let a = words!(3);
+            let b = words!(2);
+            let expected_quotient = words!(1);
+            let expected_remainder = words!(1);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([u32::MAX]);
-            let b = BigUint::new([3]);
-            let expected_quotient = BigUint::new([0x55555555]);
-            let expected_remainder = BigUint::new([0]);
+            let a = words!(u32::MAX);
+            let b = words!(3);
+            let expected_quotient = words!(0x55555555);
+            let expected_remainder = words!(0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([u32::MAX, u32::MAX - 1]);
-            let b = BigUint::new([u32::MAX, 0]);
-            let expected_quotient = BigUint::new([u32::MAX, 0]);
-            let expected_remainder = BigUint::new([u32::MAX - 1, 0]);
+            let a = words!(u32::MAX, u32::MAX - 1);
+            let b = words!(u32::MAX, 0);
+            let expected_quotient = words!(u32::MAX, 0);
+            let expected_remainder = words!(u32::MAX - 1, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x00005678, 0x00001234]);
-            let b = BigUint::new([0x00009abc, 0]);
-            let expected_quotient = BigUint::new([0x1e1dba76, 0]);
-            let expected_remainder = BigUint::new([0x6bd0, 0]);
+            let a = words!(0x00005678, 0x00001234);
+            let b = words!(0x00009abc, 0);
+            let expected_quotient = words!(0x1e1dba76, 0);
+            let expected_remainder = words!(0x6bd0, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 7]);
-            let b = BigUint::new([0, 3]);
-            let expected_quotient = BigUint::new([2, 0]);
-            let expected_remainder = BigUint::new([0, 1]);
+            let a = words!(0, 7);
+            let b = words!(0, 3);
+            let expected_quotient = words!(2, 0);
+            let expected_remainder = words!(0, 1);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([5, 7]);
-            let b = BigUint::new([0, 3]);
-            let expected_quotient = BigUint::new([2, 0]);
-            let expected_remainder = BigUint::new([5, 1]);
+            let a = words!(5, 7);
+            let b = words!(0, 3);
+            let expected_quotient = words!(2, 0);
+            let expected_remainder = words!(5, 1);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 6]);
-            let b = BigUint::new([0, 2]);
-            let expected_quotient = BigUint::new([3, 0]);
-            let expected_remainder = BigUint::new([0, 0]);
+            let a = words!(0, 6);
+            let b = words!(0, 2);
+            let expected_quotient = words!(3, 0);
+            let expected_remainder = words!(0, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x80000000]);
-            let b = BigUint::new([0x40000001]);
-            let expected_quotient = BigUint::new([1]);
-            let expected_remainder = BigUint::new([0x3fffffff]);
+            let a = words!(0x80000000);
+            let b = words!(0x40000001);
+            let expected_quotient = words!(1);
+            let expected_remainder = words!(0x3fffffff);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 0x80000000]);
-            let b = BigUint::new([0, 0x40000001]);
-            let expected_quotient = BigUint::new([1, 0]);
-            let expected_remainder = BigUint::new([0, 0x3fffffff]);
+            let a = words!(0, 0x80000000);
+            let b = words!(0, 0x40000001);
+            let expected_quotient = words!(1, 0);
+            let expected_remainder = words!(0, 0x3fffffff);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 0x80000000]);
-            let b = BigUint::new([0x00000001, 0x40000000]);
-            let expected_quotient = BigUint::new([1, 0]);
-            let expected_remainder = BigUint::new([0xffffffff, 0x3fffffff]);
+            let a = words!(0, 0x80000000);
+            let b = words!(0x00000001, 0x40000000);
+            let expected_quotient = words!(1, 0);
+            let expected_remainder = words!(0xffffffff, 0x3fffffff);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x0000789b, 0x0000bcde]);
-            let b = BigUint::new([0x0000789a, 0x0000bcde]);
-            let expected_quotient = BigUint::new([1, 0]);
-            let expected_remainder = BigUint::new([1, 0]);
+            let a = words!(0x0000789b, 0x0000bcde);
+            let b = words!(0x0000789a, 0x0000bcde);
+            let expected_quotient = words!(1, 0);
+            let expected_remainder = words!(1, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x0000ffff, 0x0000ffff]);
-            let b = BigUint::new([0, 1]);
-            let expected_quotient = BigUint::new([0x0000ffff, 0]);
-            let expected_remainder = BigUint::new([0x0000ffff, 0]);
+            let a = words!(0x0000ffff, 0x0000ffff);
+            let b = words!(0, 1);
+            let expected_quotient = words!(0x0000ffff, 0);
+            let expected_remainder = words!(0x0000ffff, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x000089ab, 0x00004567, 0x00000123]);
-            let b = BigUint::new([0, 1, 0]);
-            let expected_quotient = BigUint::new([0x00004567, 0x00000123, 0]);
-            let expected_remainder = BigUint::new([0x000089ab, 0, 0]);
+            let a = words!(0x000089ab, 0x00004567, 0x00000123);
+            let b = words!(0, 1, 0);
+            let expected_quotient = words!(0x00004567, 0x00000123, 0);
+            let expected_remainder = words!(0x000089ab, 0, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
@@ -1011,10 +2174,10 @@ mod tests {
     #[test]
     fn div_q_estimate_exceeds_radix() {
         {
-            let a = BigUint::new([0x00000000, 0x0000fffe, 0x00008000]);
-            let b = BigUint::new([0x0000ffff, 0x00008000, 0x00000000]);
-            let expected_quotient = BigUint::new([0xffffffff, 0x00000000, 0x00000000]);
-            let expected_remainder = BigUint::new([0x0000ffff, 0x00007fff, 0x00000000]);
+            let a = words!(0x00000000, 0x0000fffe, 0x00008000);
+            let b = words!(0x0000ffff, 0x00008000, 0x00000000);
+            let expected_quotient = words!(0xffffffff, 0x00000000, 0x00000000);
+            let expected_remainder = words!(0x0000ffff, 0x00007fff, 0x00000000);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
@@ -1022,24 +2185,24 @@ mod tests {
     #[test]
     fn div_q_estimate_off_by_one() {
         {
-            let a = BigUint::new([0x00000003, 0x00000000, 0x80000000, 0]);
-            let b = BigUint::new([0x00000001, 0x00000000, 0x20000000, 0]);
-            let expected_quotient = BigUint::new([0x00000003, 0, 0, 0]);
-            let expected_remainder = BigUint::new([0, 0, 0x20000000, 0]);
+            let a = words!(0x00000003, 0x00000000, 0x80000000, 0);
+            let b = words!(0x00000001, 0x00000000, 0x20000000, 0);
+            let expected_quotient = words!(0x00000003, 0, 0, 0);
+            let expected_remainder = words!(0, 0, 0x20000000, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0x00000003, 0x00000000, 0x00008000, 0]);
-            let b = BigUint::new([0x00000001, 0x00000000, 0x00002000, 0]);
-            let expected_quotient = BigUint::new([0x00000003, 0, 0, 0]);
-            let expected_remainder = BigUint::new([0, 0, 0x00002000, 0]);
+            let a = words!(0x00000003, 0x00000000, 0x00008000, 0);
+            let b = words!(0x00000001, 0x00000000, 0x00002000, 0);
+            let expected_quotient = words!(0x00000003, 0, 0, 0);
+            let expected_remainder = words!(0, 0, 0x00002000, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 0, 0x00008000, 0x00007fff]);
-            let b = BigUint::new([1, 0, 0x00008000, 0]);
-            let expected_quotient = BigUint::new([0xfffe0000, 0, 0, 0]);
-            let expected_remainder = BigUint::new([0x00020000, 0xffffffff, 0x00007fff, 0]);
+            let a = words!(0, 0, 0x00008000, 0x00007fff);
+            let b = words!(1, 0, 0x00008000, 0);
+            let expected_quotient = words!(0xfffe0000, 0, 0, 0);
+            let expected_remainder = words!(0x00020000, 0xffffffff, 0x00007fff, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
@@ -1047,24 +2210,24 @@ mod tests {
     #[test]
     fn div_mul_and_sub_cannot_be_signed() {
         {
-            let a = BigUint::new([0, 0x0000fffe, 0, 0x00008000]);
-            let b = BigUint::new([0x0000ffff, 0, 0x00008000, 0]);
-            let expected_quotient = BigUint::new([0xffffffff, 0, 0, 0]);
-            let expected_remainder = BigUint::new([0x0000ffff, 0xffffffff, 0x00007fff, 0]);
+            let a = words!(0, 0x0000fffe, 0, 0x00008000);
+            let b = words!(0x0000ffff, 0, 0x00008000, 0);
+            let expected_quotient = words!(0xffffffff, 0, 0, 0);
+            let expected_remainder = words!(0x0000ffff, 0xffffffff, 0x00007fff, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 0xfffffffe, 0, 0x80000000]);
-            let b = BigUint::new([0x0000ffff, 0, 0x80000000, 0]);
-            let expected_quotient = BigUint::new([0x00000000, 1, 0, 0]);
-            let expected_remainder = BigUint::new([0x00000000, 0xfffeffff, 0x00000000, 0]);
+            let a = words!(0, 0xfffffffe, 0, 0x80000000);
+            let b = words!(0x0000ffff, 0, 0x80000000, 0);
+            let expected_quotient = words!(0x00000000, 1, 0, 0);
+            let expected_remainder = words!(0x00000000, 0xfffeffff, 0x00000000, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
         {
-            let a = BigUint::new([0, 0xfffffffe, 0, 0x80000000]);
-            let b = BigUint::new([0xffffffff, 0, 0x80000000, 0]);
-            let expected_quotient = BigUint::new([0xffffffff, 0, 0, 0]);
-            let expected_remainder = BigUint::new([0xffffffff, 0xffffffff, 0x7fffffff, 0]);
+            let a = words!(0, 0xfffffffe, 0, 0x80000000);
+            let b = words!(0xffffffff, 0, 0x80000000, 0);
+            let expected_quotient = words!(0xffffffff, 0, 0, 0);
+            let expected_remainder = words!(0xffffffff, 0xffffffff, 0x7fffffff, 0);
             assert_eq!((expected_quotient, expected_remainder), a.div_rem(b));
         }
     }
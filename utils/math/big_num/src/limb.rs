@@ -0,0 +1,12 @@
+// 64-bit targets multiply two u64 limbs natively, while wasm32 lowers u128 arithmetic to several
+// calls, so it keeps u32 limbs. The limb32 feature forces u32 limbs everywhere, e.g. to test them.
+// Products of two limbs, plus carries, are computed in the double width type.
+#[cfg(not(any(target_arch = "wasm32", feature = "limb32")))]
+pub type Word = u64;
+#[cfg(not(any(target_arch = "wasm32", feature = "limb32")))]
+pub type DoubleWord = u128;
+
+#[cfg(any(target_arch = "wasm32", feature = "limb32"))]
+pub type Word = u32;
+#[cfg(any(target_arch = "wasm32", feature = "limb32"))]
+pub type DoubleWord = u64;
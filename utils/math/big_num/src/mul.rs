@@ -0,0 +1,257 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{DoubleWord, Word};
+
+// Operands with at least this many limbs are split with Karatsuba, smaller ones use the comba
+// methods. The recursion allocates its partial products, so it only starts to win over comba at
+// around 1536-bit operands, which is 48 u32 limbs or 24 u64 limbs.
+pub const KARATSUBA_THRESHOLD: usize = 1536 / Word::BITS as usize;
+
+fn trim(a: &[Word]) -> &[Word] {
+    let digits = a.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+    &a[..digits]
+}
+
+// Sum of a column of limb products, which can outgrow a double word by a few bits
+#[derive(Default)]
+struct Accumulator {
+    low: DoubleWord,
+    high: Word,
+}
+
+impl Accumulator {
+    fn add_product(&mut self, a: Word, b: Word) {
+        let (low, overflow) = self
+            .low
+            .overflowing_add(DoubleWord::from(a) * DoubleWord::from(b));
+        self.low = low;
+        self.high += Word::from(overflow);
+    }
+
+    // Takes the lowest limb out and carries the rest into the next column
+    fn next_limb(&mut self) -> Word {
+        let limb = self.low as Word;
+        self.low = (self.low >> Word::BITS) | (DoubleWord::from(self.high) << Word::BITS);
+        self.high = 0;
+
+        limb
+    }
+}
+
+// Product scanning (comba): every column of the product is accumulated before it is written out,
+// so each output limb is stored once
+fn mul_comba(a: &[Word], b: &[Word]) -> Vec<Word> {
+    let mut w = vec![0; a.len() + b.len()];
+    let mut acc = Accumulator::default();
+
+    for (k, limb) in w.iter_mut().enumerate() {
+        let start = k.saturating_sub(b.len() - 1);
+        for i in start..a.len().min(k + 1) {
+            acc.add_product(a[i], b[k - i]);
+        }
+
+        *limb = acc.next_limb();
+    }
+
+    w
+}
+
+// Every cross product a[i] * a[j] with i < j shows up twice in the square, so it is only computed
+// once and added twice, next to the diagonal term a[i]^2
+fn square_comba(a: &[Word]) -> Vec<Word> {
+    let mut w = vec![0; 2 * a.len()];
+    let mut acc = Accumulator::default();
+
+    for (k, limb) in w.iter_mut().enumerate() {
+        let start = k.saturating_sub(a.len() - 1);
+        for i in start..k.div_ceil(2) {
+            let (low, overflow) =
+                (DoubleWord::from(a[i]) * DoubleWord::from(a[k - i])).overflowing_mul(2);
+            acc.high += Word::from(overflow);
+            let (sum, overflow) = acc.low.overflowing_add(low);
+            acc.low = sum;
+            acc.high += Word::from(overflow);
+        }
+
+        if k % 2 == 0 {
+            acc.add_product(a[k / 2], a[k / 2]);
+        }
+
+        *limb = acc.next_limb();
+    }
+
+    w
+}
+
+fn add(a: &[Word], b: &[Word]) -> Vec<Word> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut w = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = DoubleWord::from(limb) + DoubleWord::from(*short.get(i).unwrap_or(&0)) + carry;
+        w.push(sum as Word);
+        carry = sum >> Word::BITS;
+    }
+    w.push(carry as Word);
+
+    w
+}
+
+// a -= b, where a >= b
+fn sub_assign(a: &mut [Word], b: &[Word]) {
+    let mut borrow = false;
+
+    for (i, limb) in a.iter_mut().enumerate() {
+        let (difference, b1) = limb.overflowing_sub(*b.get(i).unwrap_or(&0));
+        let (difference, b2) = difference.overflowing_sub(Word::from(borrow));
+        *limb = difference;
+        borrow = b1 || b2;
+    }
+}
+
+// w += a * 2^(Word::BITS * offset)
+fn add_at(w: &mut Vec<Word>, a: &[Word], offset: usize) {
+    let a = trim(a);
+    if w.len() < offset + a.len() + 1 {
+        w.resize(offset + a.len() + 1, 0);
+    }
+
+    let mut carry = 0;
+    for (i, limb) in w[offset..].iter_mut().enumerate() {
+        if i >= a.len() && carry == 0 {
+            break;
+        }
+
+        let sum = DoubleWord::from(*limb) + DoubleWord::from(*a.get(i).unwrap_or(&0)) + carry;
+        *limb = sum as Word;
+        carry = sum >> Word::BITS;
+    }
+}
+
+// Joins z0 + z1 * B^m + z2 * B^2m where z1 still holds (a0 + a1)(b0 + b1)
+fn combine(z0: Vec<Word>, mut z1: Vec<Word>, z2: Vec<Word>, m: usize, len: usize) -> Vec<Word> {
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut w = z0;
+    add_at(&mut w, &z1, m);
+    add_at(&mut w, &z2, 2 * m);
+    w.resize(len, 0);
+
+    w
+}
+
+// Product of two little endian limb slices, a.len() + b.len() limbs long
+pub(crate) fn mul(a: &[Word], b: &[Word], threshold: usize) -> Vec<Word> {
+    // smaller thresholds would stop the half sums from shrinking
+    assert!(threshold >= 4, "Karatsuba threshold must be at least 4");
+
+    let len = a.len() + b.len();
+    let (a, b) = (trim(a), trim(b));
+
+    if a.len().min(b.len()) < threshold {
+        let mut w = if a.is_empty() || b.is_empty() {
+            Vec::new()
+        } else {
+            mul_comba(a, b)
+        };
+        w.resize(len, 0);
+        return w;
+    }
+
+    let m = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+
+    let z0 = mul(a0, b0, threshold);
+    let z2 = mul(a1, b1, threshold);
+    let z1 = mul(&add(a0, a1), &add(b0, b1), threshold);
+
+    combine(z0, z1, z2, m, len)
+}
+
+pub(crate) fn square(a: &[Word], threshold: usize) -> Vec<Word> {
+    assert!(threshold >= 4, "Karatsuba threshold must be at least 4");
+
+    let len = 2 * a.len();
+    let a = trim(a);
+
+    if a.len() < threshold {
+        let mut w = if a.is_empty() {
+            Vec::new()
+        } else {
+            square_comba(a)
+        };
+        w.resize(len, 0);
+        return w;
+    }
+
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+
+    let z0 = square(a0, threshold);
+    let z2 = square(a1, threshold);
+    let z1 = square(&add(a0, a1), threshold);
+
+    combine(z0, z1, z2, m, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::xorshift;
+
+    fn random_limbs(seed: &mut u64, len: usize) -> Vec<Word> {
+        (0..len).map(|_| xorshift(seed) as Word).collect()
+    }
+
+    #[test]
+    fn karatsuba_matches_comba() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+
+        for (a_len, b_len) in [(4, 4), (5, 9), (16, 16), (31, 17), (64, 64), (100, 7)] {
+            for threshold in [4, 5, 8, 16] {
+                let a = random_limbs(&mut seed, a_len);
+                let b = random_limbs(&mut seed, b_len);
+
+                assert_eq!(
+                    mul_comba(&a, &b),
+                    mul(&a, &b, threshold),
+                    "{}x{} limbs, threshold {}",
+                    a_len,
+                    b_len,
+                    threshold
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn square_matches_mul() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+
+        for len in [1, 2, 3, 4, 7, 16, 33, 64] {
+            let a = random_limbs(&mut seed, len);
+
+            assert_eq!(mul_comba(&a, &a), square_comba(&a), "{} limbs", len);
+            for threshold in [4, 5, 8] {
+                assert_eq!(mul_comba(&a, &a), square(&a, threshold), "{} limbs", len);
+            }
+        }
+    }
+
+    #[test]
+    fn all_ones_carries() {
+        let a = vec![Word::MAX; 40];
+
+        assert_eq!(mul_comba(&a, &a), mul(&a, &a, 4));
+        assert_eq!(mul_comba(&a, &a), square(&a, 4));
+    }
+
+    #[test]
+    fn zero_operands() {
+        assert_eq!(vec![0; 12], mul(&[0; 8], &[1, 2, 3, 4], 4));
+        assert_eq!(vec![0; 8], square(&[0, 0, 0, 0], 4));
+    }
+}
@@ -1,19 +1,30 @@
-use crate::BigUint;
-use numeric::{FromBeBytes, Narrow, ToBeBytes, Widen};
-
-pub const RADIX: u64 = u32::MAX as u64 + 1;
-
-pub type U192 = BigUint<6>;
-pub type U256 = BigUint<8>;
-pub type U320 = BigUint<10>;
-pub type U384 = BigUint<12>;
-pub type U512 = BigUint<16>;
-pub type U640 = BigUint<20>;
-pub type U768 = BigUint<24>;
-pub type U1024 = BigUint<32>;
-pub type U1280 = BigUint<40>;
-pub type U2048 = BigUint<64>;
-pub type U4096 = BigUint<128>;
+use alloc::{vec, vec::Vec};
+
+use crate::{BigUint, DoubleWord, Word};
+use numeric::{
+    BitLength, FromBeBytes, FromBeSlice, FromLeBytes, Narrow, ToBeBytes, ToBeSlice, ToLeBytes,
+    Widen,
+};
+
+pub const RADIX: DoubleWord = 1 << Word::BITS;
+
+const LIMB_BYTES: usize = core::mem::size_of::<Word>();
+
+const fn limbs(bits: usize) -> usize {
+    bits / Word::BITS as usize
+}
+
+pub type U192 = BigUint<{ limbs(192) }>;
+pub type U256 = BigUint<{ limbs(256) }>;
+pub type U320 = BigUint<{ limbs(320) }>;
+pub type U384 = BigUint<{ limbs(384) }>;
+pub type U512 = BigUint<{ limbs(512) }>;
+pub type U640 = BigUint<{ limbs(640) }>;
+pub type U768 = BigUint<{ limbs(768) }>;
+pub type U1024 = BigUint<{ limbs(1024) }>;
+pub type U1280 = BigUint<{ limbs(1280) }>;
+pub type U2048 = BigUint<{ limbs(2048) }>;
+pub type U4096 = BigUint<{ limbs(4096) }>;
 
 macro_rules! impl_narrow {
     ($($t:ty)*) => {
@@ -60,10 +71,10 @@ macro_rules! impl_from_be_bytes {
                 type Bytes = [u8; <$t>::BYTES];
 
                 fn from_be_bytes(bytes: &Self::Bytes) -> Self {
-                    let mut limbs = [0u32; <$t>::LIMBS];
+                    let mut limbs = [0; <$t>::LIMBS];
 
-                    for (i, chunk) in bytes.chunks_exact(4).rev().enumerate() {
-                        limbs[i] = u32::from_be_bytes(chunk.try_into().unwrap());
+                    for (i, chunk) in bytes.chunks_exact(LIMB_BYTES).rev().enumerate() {
+                        limbs[i] = Word::from_be_bytes(chunk.try_into().unwrap());
                     }
 
                     Self::new(limbs)
@@ -86,8 +97,8 @@ macro_rules! impl_to_be_bytes {
 
                     for (i, &limb) in self.limbs.iter().rev().enumerate() {
                         let be_bytes = limb.to_be_bytes();
-                        let start = 4 * i;
-                        let end = start + 4;
+                        let start = LIMB_BYTES * i;
+                        let end = start + LIMB_BYTES;
                         bytes[start..end].copy_from_slice(&be_bytes);
                     }
 
@@ -99,3 +110,207 @@ macro_rules! impl_to_be_bytes {
 }
 
 impl_to_be_bytes!(U192 U256 U320 U384 U512 U640 U768 U1024 U1280 U2048 U4096);
+
+// Limbs are stored least significant first, so little endian bytes follow them in order
+macro_rules! impl_from_le_bytes {
+    ($($t:ty)*) => {
+        $(
+            impl FromLeBytes for $t {
+                type Bytes = [u8; <$t>::BYTES];
+
+                fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+                    let mut limbs = [0; <$t>::LIMBS];
+
+                    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(LIMB_BYTES)) {
+                        *limb = Word::from_le_bytes(chunk.try_into().unwrap());
+                    }
+
+                    Self::new(limbs)
+                }
+            }
+        )*
+    }
+}
+
+impl_from_le_bytes!(U192 U256 U320 U384 U512 U640 U768 U1024 U1280 U2048 U4096);
+
+macro_rules! impl_to_le_bytes {
+    ($($t:ty)*) => {
+        $(
+            impl ToLeBytes for $t {
+                type Bytes = [u8; <$t>::BYTES];
+
+                fn to_le_bytes(&self) -> Self::Bytes {
+                    let mut bytes = [0u8; <$t>::BYTES];
+
+                    for (chunk, limb) in bytes.chunks_exact_mut(LIMB_BYTES).zip(self.limbs) {
+                        chunk.copy_from_slice(&limb.to_le_bytes());
+                    }
+
+                    bytes
+                }
+            }
+        )*
+    }
+}
+
+impl_to_le_bytes!(U192 U256 U320 U384 U512 U640 U768 U1024 U1280 U2048 U4096);
+
+impl<const N: usize> BigUint<N> {
+    // Leading zero bytes are ignored, None if the value does not fit
+    pub fn from_le_slice(bytes: &[u8]) -> Option<Self> {
+        BigUint::from_le_iter(bytes.iter())
+    }
+
+    fn from_le_iter<'a>(bytes: impl Iterator<Item = &'a u8>) -> Option<Self> {
+        let mut limbs = [0; N];
+
+        for (i, &byte) in bytes.enumerate() {
+            match limbs.get_mut(i / LIMB_BYTES) {
+                Some(limb) => *limb |= Word::from(byte) << (8 * (i % LIMB_BYTES)),
+                None if byte != 0 => return None,
+                None => {}
+            }
+        }
+
+        Some(BigUint::new(limbs))
+    }
+
+    // Big endian bytes without leading zeros, so zero is empty
+    pub fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        let mut bytes = vec![0; self.bit_length().div_ceil(8)];
+        self.to_be_slice(&mut bytes);
+
+        bytes
+    }
+
+    // Big endian bytes padded to len, e.g. 66 bytes for P-521 field elements. None if the value
+    // needs more bytes than that
+    pub fn to_be_bytes_padded(&self, len: usize) -> Option<Vec<u8>> {
+        let mut bytes = vec![0; len];
+        self.to_be_slice(&mut bytes)?;
+
+        Some(bytes)
+    }
+}
+
+impl<const N: usize> FromBeSlice for BigUint<N> {
+    fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        BigUint::from_le_iter(bytes.iter().rev())
+    }
+}
+
+impl<const N: usize> ToBeSlice for BigUint<N> {
+    fn to_be_slice(&self, out: &mut [u8]) -> Option<()> {
+        let offset = out.len().checked_sub(self.bit_length().div_ceil(8))?;
+        out[..offset].fill(0);
+
+        for (i, byte) in out[offset..].iter_mut().rev().enumerate() {
+            *byte = (self.limbs[i / LIMB_BYTES] >> (8 * (i % LIMB_BYTES))) as u8;
+        }
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_be_and_le_slice() {
+        let be = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
+        let le = [0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01];
+        let expected = U256::from_words([0x06070809, 0x02030405, 0x01]);
+
+        assert_eq!(Some(expected), U256::from_be_slice(&be));
+        assert_eq!(Some(expected), U256::from_le_slice(&le));
+        assert_eq!(Some(U256::default()), U256::from_be_slice(&[]));
+    }
+
+    #[test]
+    fn from_slice_ignores_leading_zeros() {
+        let mut be = [0; 40];
+        be[39] = 7;
+        let mut le = [0; 40];
+        le[0] = 7;
+
+        assert_eq!(Some(U256::from(7u8)), U256::from_be_slice(&be));
+        assert_eq!(Some(U256::from(7u8)), U256::from_le_slice(&le));
+
+        be[7] = 1;
+        le[32] = 1;
+        assert_eq!(None, U256::from_be_slice(&be));
+        assert_eq!(None, U256::from_le_slice(&le));
+    }
+
+    #[test]
+    fn to_be_bytes_trimmed() {
+        assert_eq!(Vec::<u8>::new(), U256::default().to_be_bytes_trimmed());
+        assert_eq!(
+            vec![0x01, 0x00],
+            U256::from_words([0x0100]).to_be_bytes_trimmed()
+        );
+        assert_eq!(
+            vec![0x01, 0x02, 0x03, 0x04, 0x05],
+            U256::from_words([0x02030405, 0x01]).to_be_bytes_trimmed()
+        );
+    }
+
+    #[test]
+    fn to_be_bytes_padded() {
+        let n = U640::from_words([0x02030405, 0x01]);
+
+        let padded = n.to_be_bytes_padded(66).unwrap();
+        assert_eq!(66, padded.len());
+        assert_eq!([0x01, 0x02, 0x03, 0x04, 0x05], padded[61..]);
+        assert!(padded[..61].iter().all(|&byte| byte == 0));
+
+        assert_eq!(Some(vec![1, 2, 3, 4, 5]), n.to_be_bytes_padded(5));
+        assert_eq!(None, n.to_be_bytes_padded(4));
+        assert_eq!(Some(vec![]), U640::default().to_be_bytes_padded(0));
+    }
+
+    macro_rules! test_le_bytes_round_trip {
+        ($($t:ty)*) => {
+            $(
+                let bytes: Vec<u8> = (0..<$t>::BYTES).map(|i| (7 * i + 1) as u8).collect();
+                let n = <$t>::from_be_slice(&bytes).unwrap();
+
+                let le = n.to_le_bytes();
+                let mut be = n.to_be_bytes();
+                be.reverse();
+
+                assert_eq!(be, le, "{}", stringify!($t));
+                assert_eq!(n, <$t>::from_le_bytes(&le), "{}", stringify!($t));
+                assert_eq!(Some(n), <$t>::from_le_slice(&le), "{}", stringify!($t));
+            )*
+        };
+    }
+
+    #[test]
+    fn le_bytes_round_trip() {
+        test_le_bytes_round_trip!(U192 U256 U320 U384 U512 U640 U768 U1024 U1280 U2048 U4096);
+    }
+
+    #[test]
+    fn le_bytes_order() {
+        let n = U256::from_words([0x04030201, 0x08070605]);
+        let le = n.to_le_bytes();
+
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], le[..8]);
+        assert!(le[8..].iter().all(|&byte| byte == 0));
+        assert_eq!(0x0807060504030201u64.to_le_bytes(), le[..8]);
+    }
+
+    #[test]
+    fn slice_round_trip() {
+        let n = U384::from_words([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+
+        assert_eq!(Some(n), U384::from_be_slice(&n.to_be_bytes_trimmed()));
+        assert_eq!(
+            n.to_be_bytes().as_ref(),
+            n.to_be_bytes_padded(U384::BYTES).unwrap()
+        );
+    }
+}
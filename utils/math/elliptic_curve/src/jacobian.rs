@@ -0,0 +1,291 @@
+use modular::ModularArithmetic;
+use numeric::Choice;
+
+use crate::{Curve, Numeric, Point};
+
+// (x, y, z) represents the affine point (x / z^2, y / z^3), and any point with z = 0 is the identity
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct JacobianPoint<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Numeric> JacobianPoint<T> {
+    pub fn identity() -> Self {
+        JacobianPoint {
+            x: T::one(),
+            y: T::one(),
+            z: T::zero(),
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.z == T::zero()
+    }
+
+    pub fn ct_is_identity(&self) -> Choice {
+        self.z.ct_eq(&T::zero())
+    }
+
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        JacobianPoint {
+            x: T::conditional_select(&a.x, &b.x, choice),
+            y: T::conditional_select(&a.y, &b.y, choice),
+            z: T::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+
+    pub fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        T::conditional_swap(&mut a.x, &mut b.x, choice);
+        T::conditional_swap(&mut a.y, &mut b.y, choice);
+        T::conditional_swap(&mut a.z, &mut b.z, choice);
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T>> Curve<T, R> {
+    // Jacobian coordinates are kept in the representation of the curve's field backend
+    pub fn to_jacobian(&self, p: &Point<T>) -> JacobianPoint<T> {
+        match p {
+            Point::Identity => JacobianPoint::identity(),
+            Point::Affine { x, y } => JacobianPoint {
+                x: self.ring.to_repr(*x),
+                y: self.ring.to_repr(*y),
+                z: self.ring.to_repr(T::one()),
+            },
+        }
+    }
+
+    pub fn to_affine(&self, p: &JacobianPoint<T>) -> Point<T> {
+        if p.is_identity() {
+            return Point::Identity;
+        }
+
+        let z_inv = self.inv(p.z);
+        let z_inv2 = self.ring.mul(z_inv, z_inv);
+        let z_inv3 = self.ring.mul(z_inv2, z_inv);
+
+        Point::new(
+            self.ring.to_residue(self.ring.mul(p.x, z_inv2)),
+            self.ring.to_residue(self.ring.mul(p.y, z_inv3)),
+        )
+    }
+
+    pub fn double_jacobian(&self, p: &JacobianPoint<T>) -> JacobianPoint<T> {
+        if p.is_identity() || p.y == T::zero() {
+            return JacobianPoint::identity();
+        }
+
+        self.double_formula(p)
+    }
+
+    pub fn add_jacobian(&self, p: &JacobianPoint<T>, q: &JacobianPoint<T>) -> JacobianPoint<T> {
+        if p.is_identity() {
+            return *q;
+        }
+        if q.is_identity() {
+            return *p;
+        }
+
+        let (sum, h, r) = self.add_formula(p, q);
+
+        if h == T::zero() {
+            // both points share an x coordinate, so q is either p or -p
+            return if r == T::zero() {
+                self.double_jacobian(p)
+            } else {
+                JacobianPoint::identity()
+            };
+        }
+
+        sum
+    }
+
+    // Unlike `double_jacobian` this never returns early: z3 = 2yz is zero for the identity and
+    // for points with y = 0, so the formula itself yields the identity for them
+    pub fn double_jacobian_ct(&self, p: &JacobianPoint<T>) -> JacobianPoint<T> {
+        self.double_formula(p)
+    }
+
+    // Complete addition without branches. The cases the formula gets wrong, an identity operand
+    // and q = p, are always computed too and selected in by their conditions. For q = -p the
+    // formula already gives z = 0.
+    pub fn add_jacobian_ct(&self, p: &JacobianPoint<T>, q: &JacobianPoint<T>) -> JacobianPoint<T> {
+        let (sum, h, r) = self.add_formula(p, q);
+        let double = self.double_formula(p);
+
+        let is_double = h.ct_eq(&T::zero()) & r.ct_eq(&T::zero());
+        let res = JacobianPoint::conditional_select(&sum, &double, is_double);
+        let res = JacobianPoint::conditional_select(&res, q, p.ct_is_identity());
+        JacobianPoint::conditional_select(&res, p, q.ct_is_identity())
+    }
+
+    fn double_formula(&self, p: &JacobianPoint<T>) -> JacobianPoint<T> {
+        let ring = &self.ring;
+
+        let xx = ring.mul(p.x, p.x);
+        let yy = ring.mul(p.y, p.y);
+        let yyyy = ring.mul(yy, yy);
+        let zz = ring.mul(p.z, p.z);
+
+        // small constant multiples are computed with additions, which work in any representation
+        let xyy = ring.mul(p.x, yy);
+        let xyy2 = ring.add(xyy, xyy);
+        let s = ring.add(xyy2, xyy2);
+        let m = ring.add(
+            ring.add(ring.add(xx, xx), xx),
+            ring.mul(self.a, ring.mul(zz, zz)),
+        );
+
+        let yyyy2 = ring.add(yyyy, yyyy);
+        let yyyy4 = ring.add(yyyy2, yyyy2);
+        let yyyy8 = ring.add(yyyy4, yyyy4);
+        let yz = ring.mul(p.y, p.z);
+
+        let x = ring.sub(ring.mul(m, m), ring.add(s, s));
+        let y = ring.sub(ring.mul(m, ring.sub(s, x)), yyyy8);
+        let z = ring.add(yz, yz);
+
+        JacobianPoint { x, y, z }
+    }
+
+    // The sum for p != ±q with neither being the identity, along with h = u2 - u1 and
+    // r = s2 - s1, which tell the other cases apart
+    fn add_formula(&self, p: &JacobianPoint<T>, q: &JacobianPoint<T>) -> (JacobianPoint<T>, T, T) {
+        let ring = &self.ring;
+
+        let z1z1 = ring.mul(p.z, p.z);
+        let z2z2 = ring.mul(q.z, q.z);
+        let u1 = ring.mul(p.x, z2z2);
+        let u2 = ring.mul(q.x, z1z1);
+        let s1 = ring.mul(p.y, ring.mul(q.z, z2z2));
+        let s2 = ring.mul(q.y, ring.mul(p.z, z1z1));
+
+        let h = ring.sub(u2, u1);
+        let r = ring.sub(s2, s1);
+
+        let hh = ring.mul(h, h);
+        let hhh = ring.mul(hh, h);
+        let u1hh = ring.mul(u1, hh);
+
+        let x = ring.sub(ring.sub(ring.mul(r, r), hhh), ring.add(u1hh, u1hh));
+        let y = ring.sub(ring.mul(r, ring.sub(u1hh, x)), ring.mul(s1, hhh));
+        let z = ring.mul(h, ring.mul(p.z, q.z));
+
+        (JacobianPoint { x, y, z }, h, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTS: [Point<i32>; 18] = [
+        Point::Identity,
+        Point::new(0, 6),
+        Point::new(0, 7),
+        Point::new(1, 2),
+        Point::new(1, 11),
+        Point::new(2, 2),
+        Point::new(2, 11),
+        Point::new(3, 4),
+        Point::new(3, 9),
+        Point::new(5, 3),
+        Point::new(5, 10),
+        Point::new(9, 0),
+        Point::new(10, 2),
+        Point::new(10, 11),
+        Point::new(11, 4),
+        Point::new(11, 9),
+        Point::new(12, 4),
+        Point::new(12, 9),
+    ];
+
+    #[test]
+    fn affine_round_trip() {
+        let curve = Curve::new(-7, 10, 13);
+
+        for p in POINTS {
+            assert_eq!(p, curve.to_affine(&curve.to_jacobian(&p)));
+        }
+    }
+
+    #[test]
+    fn add_matches_affine() {
+        let curve = Curve::new(-7, 10, 13);
+
+        for p in POINTS {
+            for q in POINTS {
+                let sum = curve.add_jacobian(&curve.to_jacobian(&p), &curve.to_jacobian(&q));
+                assert_eq!(
+                    curve.add(&p, &q),
+                    curve.to_affine(&sum),
+                    "{:?} + {:?}",
+                    p,
+                    q
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn double_matches_affine() {
+        let curve = Curve::new(-7, 10, 13);
+
+        for p in POINTS {
+            let double = curve.double_jacobian(&curve.to_jacobian(&p));
+            assert_eq!(curve.add(&p, &p), curve.to_affine(&double), "2 * {:?}", p);
+        }
+    }
+
+    #[test]
+    fn complete_formulas_match_affine() {
+        let curve = Curve::new(-7, 10, 13);
+
+        for p in POINTS {
+            let jp = curve.to_jacobian(&p);
+            let double = curve.double_jacobian_ct(&jp);
+            assert_eq!(curve.add(&p, &p), curve.to_affine(&double), "2 * {:?}", p);
+
+            for q in POINTS {
+                let sum = curve.add_jacobian_ct(&jp, &curve.to_jacobian(&q));
+                assert_eq!(
+                    curve.add(&p, &q),
+                    curve.to_affine(&sum),
+                    "{:?} + {:?}",
+                    p,
+                    q
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ct_swap() {
+        let curve = Curve::new(-7, 10, 13);
+        let p = curve.to_jacobian(&Point::new(11, 4));
+        let q = curve.to_jacobian(&Point::new(9, 0));
+
+        let (mut a, mut b) = (p, q);
+        JacobianPoint::ct_swap(&mut a, &mut b, Choice::from(false));
+        assert_eq!((p, q), (a, b));
+
+        JacobianPoint::ct_swap(&mut a, &mut b, Choice::from(true));
+        assert_eq!((q, p), (a, b));
+    }
+
+    #[test]
+    fn add_with_non_unit_z() {
+        let curve = Curve::new(-7, 10, 13);
+
+        // 2P and 3P both have z != 1, so this exercises the full addition formula
+        let p = curve.to_jacobian(&Point::new(11, 4));
+        let p2 = curve.double_jacobian(&p);
+        let p3 = curve.add_jacobian(&p2, &p);
+
+        assert_eq!(
+            curve.mul_affine(&Point::new(11, 4), &5),
+            curve.to_affine(&curve.add_jacobian(&p2, &p3))
+        );
+    }
+}
@@ -1,29 +1,56 @@
 #![no_std]
 
-use core::ops::{BitAnd, Shr};
-use modular::{Narrowed, Ring, Widened};
-use numeric::{Bit, LeadingZeros, Widen};
+mod jacobian;
+mod multi;
+mod sec1;
+mod window;
 
-pub trait Numeric: modular::Narrowed + From<u8> + Bit + LeadingZeros {}
+pub use jacobian::JacobianPoint;
+pub use multi::MAX_MULTI_TERMS;
+pub use sec1::DecodingError;
+pub use window::FixedBaseTable;
 
-impl<T> Numeric for T where T: modular::Narrowed + From<u8> + Bit + LeadingZeros {}
+extern crate alloc;
+
+use modular::{Fp, ModularArithmetic, Ring, Widened};
+use numeric::{Bit, BitLength, Choice, ConditionallySelectable, ConstantTimeEq, Widen};
+
+pub trait Numeric:
+    modular::Narrowed + From<u8> + Bit + BitLength + ConditionallySelectable + ConstantTimeEq
+{
+}
+
+impl<T> Numeric for T where
+    T: modular::Narrowed + From<u8> + Bit + BitLength + ConditionallySelectable + ConstantTimeEq
+{
+}
 
 #[derive(Clone, PartialEq, Debug, Default)]
-pub struct Point<T> {
-    pub x: T,
-    pub y: T,
+pub enum Point<T> {
+    #[default]
+    Identity,
+    Affine {
+        x: T,
+        y: T,
+    },
 }
 
 impl<T> Point<T> {
     pub const fn new(x: T, y: T) -> Point<T> {
-        Point { x, y }
+        Point::Affine { x, y }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        matches!(self, Point::Identity)
     }
 }
 
-pub struct Curve<T> {
+// `a` and `b`, like every coordinate handled internally, are stored in the representation of the
+// field backend `R`. Points passed in and returned are always plain residues.
+pub struct Curve<T, R = Ring<T>> {
     a: T,
     b: T,
-    ring: Ring<T>,
+    ring: R,
 }
 
 impl<T: Numeric> Curve<T>
@@ -31,90 +58,206 @@ where
     <T as Widen>::Output: Widened<T>,
 {
     pub fn new(a: T, b: T, modulus: T) -> Curve<T> {
+        Curve::with_ring(a, b, Ring::new(modulus))
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T>> Curve<T, R> {
+    pub fn with_ring(a: T, b: T, ring: R) -> Curve<T, R> {
         Curve {
-            a,
-            b,
-            ring: Ring::new(modulus),
+            a: ring.to_repr(a),
+            b: ring.to_repr(b),
+            ring,
         }
     }
 
-    pub fn add(&self, p: &Point<T>, q: &Point<T>) -> Option<Point<T>> {
-        let three = T::from(3);
-        let two = T::from(2);
+    pub fn modulus(&self) -> T {
+        self.ring.modulus()
+    }
+
+    pub fn neg(&self, p: &Point<T>) -> Point<T> {
+        match p {
+            Point::Identity => Point::Identity,
+            Point::Affine { x, y } => Point::new(*x, (-self.field(*y)).value()),
+        }
+    }
 
-        let slope = if p != q {
-            let num = self.ring.sub(q.y, p.y);
-            let denom = self.ring.sub(q.x, p.x);
+    // Panics if the slope denominator has no inverse, which can only happen when the modulus is not
+    // prime.
+    pub fn add(&self, p: &Point<T>, q: &Point<T>) -> Point<T> {
+        let ((px, py), (qx, qy)) = match (p, q) {
+            (Point::Identity, _) => return q.clone(),
+            (_, Point::Identity) => return p.clone(),
+            (Point::Affine { x: px, y: py }, Point::Affine { x: qx, y: qy }) => (
+                (self.field(*px), self.field(*py)),
+                (self.field(*qx), self.field(*qy)),
+            ),
+        };
 
-            match self.ring.inv(denom) {
-                Some(inv) => self.ring.mul(num, inv),
-                None => return None,
-            }
+        let slope = if px != qx {
+            (qy - py) / (qx - px)
+        } else if (py + qy).is_zero() {
+            // q is either -p or p itself with y = 0, both of which sum to the identity
+            return Point::Identity;
         } else {
-            let px2 = self.ring.mul(p.x, p.x);
-            let num = self.ring.add(self.ring.mul(three, px2), self.a);
-            let denom = self.ring.mul(two, p.y);
+            let three = self.field(T::from(3));
+            (three * px * px + self.a()) / (py + py)
+        };
+
+        let x = slope * slope - px - qx;
+        let y = slope * (px - x) - py;
 
-            match self.ring.inv(denom) {
-                Some(inv) => self.ring.mul(num, inv),
-                None => return None,
+        Point::new(x.value(), y.value())
+    }
+
+    pub fn mul(&self, p: &Point<T>, d: &T) -> Point<T> {
+        let bits = d.bit_length();
+        let p = self.to_jacobian(p);
+        let mut res = JacobianPoint::identity();
+
+        for i in (0..bits).rev() {
+            res = self.double_jacobian(&res);
+
+            if d.bit(i) {
+                res = self.add_jacobian(&res, &p);
             }
-        };
+        }
 
-        let slope2 = self.ring.mul(slope, slope);
-        let x = self.ring.sub(self.ring.sub(slope2, p.x), q.x);
-        let y = self
-            .ring
-            .sub(self.ring.mul(slope, self.ring.sub(p.x, x)), p.y);
+        self.to_affine(&res)
+    }
+
+    // Montgomery ladder over every bit of `T` with the complete addition and doubling formulas, so
+    // the sequence of field operations does not depend on the value of `d`. The field arithmetic
+    // underneath is not constant time yet.
+    pub fn mul_ladder(&self, p: &Point<T>, d: &T) -> Point<T> {
+        self.to_affine(&self.ladder(&self.to_jacobian(p), d))
+    }
+
+    fn ladder(&self, p: &JacobianPoint<T>, d: &T) -> JacobianPoint<T> {
+        let bits = core::mem::size_of::<T>() * 8;
+        let mut r0 = JacobianPoint::identity();
+        let mut r1 = *p;
+
+        for i in (0..bits).rev() {
+            let bit = Choice::from(d.bit(i));
+
+            JacobianPoint::ct_swap(&mut r0, &mut r1, bit);
+            r1 = self.add_jacobian_ct(&r0, &r1);
+            r0 = self.double_jacobian_ct(&r0);
+            JacobianPoint::ct_swap(&mut r0, &mut r1, bit);
+        }
 
-        Some(Point::new(x, y))
+        r0
     }
 
-    pub fn mul(&self, p: &Point<T>, d: &T) -> Option<Point<T>> {
-        let size = core::mem::size_of::<T>() * 8;
-        let bits = size - d.leading_zeros() as usize;
-        let mut res = p.clone();
+    // Reference implementation of `mul` that stays in affine coordinates, inverting on every step
+    pub fn mul_affine(&self, p: &Point<T>, d: &T) -> Point<T> {
+        let bits = d.bit_length();
+        let mut res = Point::Identity;
 
-        for i in (0..bits - 1).rev() {
-            res = match self.add(&res, &res) {
-                Some(res) => res,
-                None => return None,
-            };
+        for i in (0..bits).rev() {
+            res = self.add(&res, &res);
 
             if d.bit(i) {
-                res = match self.add(&res, &p) {
-                    Some(res) => res,
-                    None => return None,
-                };
+                res = self.add(&res, p);
             }
         }
 
-        Some(res)
+        res
     }
 
     pub fn is_valid_point(&self, p: &Point<T>) -> bool {
-        let lhs = self.ring.mul(p.y, p.y);
-        let rhs = self.ring.add(
-            self.ring.add(
-                self.ring.mul(self.ring.mul(p.x, p.x), p.x),
-                self.ring.mul(self.a, p.x),
-            ),
-            self.b,
-        );
-        lhs == rhs
+        let (x, y) = match p {
+            Point::Identity => return true,
+            Point::Affine { x, y } => (self.field(*x), self.field(*y)),
+        };
+
+        y * y == x * x * x + self.a() * x + self.b()
+    }
+
+    fn field(&self, a: T) -> Fp<'_, T, R> {
+        Fp::new(&self.ring, a)
+    }
+
+    fn a(&self) -> Fp<'_, T, R> {
+        Fp::from_repr(&self.ring, self.a)
+    }
+
+    fn b(&self) -> Fp<'_, T, R> {
+        Fp::from_repr(&self.ring, self.b)
+    }
+
+    fn inv(&self, a: T) -> T {
+        self.ring.inv(a).expect("curve modulus must be prime")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    // Logs the kind of every field operation, so the sequences run for different scalars can be
+    // compared
+    pub(crate) struct RecordingRing {
+        ring: Ring<i32>,
+        pub(crate) log: RefCell<Vec<char>>,
+    }
+
+    impl RecordingRing {
+        pub(crate) fn curve(a: i32, b: i32, modulus: i32) -> Curve<i32, RecordingRing> {
+            Curve::with_ring(
+                a,
+                b,
+                RecordingRing {
+                    ring: Ring::new(modulus),
+                    log: RefCell::new(Vec::new()),
+                },
+            )
+        }
+    }
+
+    impl ModularArithmetic<i32> for RecordingRing {
+        fn modulus(&self) -> i32 {
+            self.ring.modulus()
+        }
+
+        fn to_repr(&self, a: i32) -> i32 {
+            self.ring.to_repr(a)
+        }
+
+        fn to_residue(&self, a: i32) -> i32 {
+            a
+        }
+
+        fn add(&self, a: i32, b: i32) -> i32 {
+            self.log.borrow_mut().push('+');
+            self.ring.add(a, b)
+        }
+
+        fn sub(&self, a: i32, b: i32) -> i32 {
+            self.log.borrow_mut().push('-');
+            self.ring.sub(a, b)
+        }
+
+        fn mul(&self, a: i32, b: i32) -> i32 {
+            self.log.borrow_mut().push('*');
+            self.ring.mul(a, b)
+        }
+
+        fn inv(&self, a: i32) -> Option<i32> {
+            self.log.borrow_mut().push('/');
+            self.ring.inv(a)
+        }
+    }
 
     #[test]
     fn add_p_not_equal_q() {
         let p = Point::new(12, 4);
         let q = Point::new(11, 4);
         let curve = Curve::new(-7, 10, 13);
-        assert_eq!(Some(Point::new(3, 9)), curve.add(&p, &q));
+        assert_eq!(Point::new(3, 9), curve.add(&p, &q));
     }
 
     #[test]
@@ -122,7 +265,7 @@ mod tests {
         let p = Point::new(12, 4);
         let q = Point::new(12, 4);
         let curve = Curve::new(-7, 10, 13);
-        assert_eq!(Some(Point::new(12, 9)), curve.add(&p, &q));
+        assert_eq!(Point::new(12, 9), curve.add(&p, &q));
     }
 
     #[test]
@@ -149,9 +292,8 @@ mod tests {
         for point in valid_points {
             assert!(
                 curve.is_valid_point(&point),
-                "Point ({}, {}) should be on the curve but was reported as invalid",
-                point.x,
-                point.y
+                "Point {:?} should be on the curve but was reported as invalid",
+                point
             );
         }
     }
@@ -160,13 +302,95 @@ mod tests {
     fn mul_double() {
         let p = Point::new(11, 4);
         let curve = Curve::new(-7, 10, 13);
-        assert_eq!(Some(Point::new(5, 3)), curve.mul(&p, &2));
+        assert_eq!(Point::new(5, 3), curve.mul(&p, &2));
     }
 
     #[test]
     fn mul_triple() {
         let p = Point::new(11, 4);
         let curve = Curve::new(-7, 10, 13);
-        assert_eq!(Some(Point::new(1, 2)), curve.mul(&p, &3));
+        assert_eq!(Point::new(1, 2), curve.mul(&p, &3));
+    }
+
+    #[test]
+    fn add_p_and_neg_p() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+        assert_eq!(Point::new(11, 9), curve.neg(&p));
+        assert_eq!(Point::Identity, curve.add(&p, &curve.neg(&p)));
+    }
+
+    #[test]
+    fn add_identity() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+        assert_eq!(p, curve.add(&p, &Point::Identity));
+        assert_eq!(p, curve.add(&Point::Identity, &p));
+        assert_eq!(
+            Point::Identity,
+            curve.add(&Point::Identity, &Point::Identity)
+        );
+    }
+
+    #[test]
+    fn double_point_with_zero_y() {
+        let p = Point::new(9, 0);
+        let curve = Curve::new(-7, 10, 13);
+        assert_eq!(Point::Identity, curve.add(&p, &p));
+    }
+
+    #[test]
+    fn mul_ladder_matches_mul() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+
+        for d in 0..40 {
+            assert_eq!(
+                curve.mul(&p, &d),
+                curve.mul_ladder(&p, &d),
+                "{} * {:?}",
+                d,
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn mul_ladder_operations_do_not_depend_on_the_scalar() {
+        let curve = RecordingRing::curve(-7, 10, 13);
+        let p = curve.to_jacobian(&Point::new(11, 4));
+        let mut logs = Vec::new();
+
+        for d in [0, 1, 2, 7, 0x7fff, i32::MAX] {
+            curve.ring.log.borrow_mut().clear();
+            curve.ladder(&p, &d);
+            logs.push(curve.ring.log.take());
+        }
+
+        assert!(!logs[0].is_empty());
+        assert!(logs.iter().all(|log| *log == logs[0]));
+    }
+
+    #[test]
+    fn mul_zero() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+        assert_eq!(Point::Identity, curve.mul(&p, &0));
+    }
+
+    #[test]
+    fn mul_group_order() {
+        let curve = Curve::new(-7, 10, 13);
+
+        // the curve has 18 points including the identity
+        assert_eq!(Point::Identity, curve.mul(&Point::new(11, 4), &18));
+        assert_eq!(Point::Identity, curve.mul(&Point::new(9, 0), &2));
+        assert_eq!(Point::Identity, curve.mul(&Point::Identity, &5));
+    }
+
+    #[test]
+    fn identity_is_valid_point() {
+        let curve = Curve::new(-7, 10, 13);
+        assert!(curve.is_valid_point(&Point::Identity));
     }
 }
@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+use modular::ModularArithmetic;
+
+use crate::{Curve, JacobianPoint, Numeric, Point};
+
+// The subset sum table of mul_multi holds 2^k - 1 points, 255 of them at this bound
+pub const MAX_MULTI_TERMS: usize = 8;
+
+impl<T: Numeric, R: ModularArithmetic<T>> Curve<T, R> {
+    // Computes d1 * p1 + d2 * p2 + ... with Straus' interleaving, sharing one chain of doublings
+    // between every term. The table of subset sums grows as 2^k, so this suits a handful of terms,
+    // and panics with more than MAX_MULTI_TERMS of them.
+    pub fn mul_multi(&self, terms: &[(&Point<T>, &T)]) -> Point<T> {
+        assert!(
+            terms.len() <= MAX_MULTI_TERMS,
+            "too many terms for a multi-scalar multiplication"
+        );
+
+        let bits = terms.iter().map(|(_, d)| d.bit_length()).max().unwrap_or(0);
+
+        // subset_sums[mask - 1] is the sum of every point whose index is set in mask
+        let mut subset_sums: Vec<JacobianPoint<T>> = Vec::with_capacity((1 << terms.len()) - 1);
+        for mask in 1usize..(1 << terms.len()) {
+            let lowest = mask.trailing_zeros() as usize;
+            let point = self.to_jacobian(terms[lowest].0);
+            let rest = mask & (mask - 1);
+
+            subset_sums.push(if rest == 0 {
+                point
+            } else {
+                self.add_jacobian(&subset_sums[rest - 1], &point)
+            });
+        }
+
+        let mut res = JacobianPoint::identity();
+
+        for i in (0..bits).rev() {
+            res = self.double_jacobian(&res);
+
+            let mask = terms
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, d))| d.bit(i))
+                .fold(0, |mask, (j, _)| mask | (1 << j));
+
+            if mask != 0 {
+                res = self.add_jacobian(&res, &subset_sums[mask - 1]);
+            }
+        }
+
+        self.to_affine(&res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_multi_matches_separate_muls() {
+        let p = Point::new(11, 4);
+        let q = Point::new(3, 9);
+        let curve = Curve::new(-7, 10, 13);
+
+        for d1 in 0..20 {
+            for d2 in 0..20 {
+                let expected = curve.add(&curve.mul(&p, &d1), &curve.mul(&q, &d2));
+                assert_eq!(expected, curve.mul_multi(&[(&p, &d1), (&q, &d2)]));
+            }
+        }
+    }
+
+    #[test]
+    fn mul_multi_three_terms() {
+        let p = Point::new(11, 4);
+        let q = Point::new(3, 9);
+        let r = Point::new(9, 0);
+        let curve = Curve::new(-7, 10, 13);
+
+        let expected = curve.add(
+            &curve.add(&curve.mul(&p, &7), &curve.mul(&q, &12)),
+            &curve.mul(&r, &5),
+        );
+        assert_eq!(expected, curve.mul_multi(&[(&p, &7), (&q, &12), (&r, &5)]));
+    }
+
+    #[test]
+    fn mul_multi_shared_point() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+
+        // p and -p cancel out in the subset sum table
+        let neg_p = curve.neg(&p);
+        assert_eq!(Point::Identity, curve.mul_multi(&[(&p, &5), (&neg_p, &5)]));
+        assert_eq!(curve.mul(&p, &11), curve.mul_multi(&[(&p, &5), (&p, &6)]));
+    }
+
+    #[test]
+    fn mul_multi_no_terms() {
+        let curve = Curve::new(-7, 10, 13);
+        assert_eq!(Point::Identity, curve.mul_multi(&[]));
+    }
+
+    #[test]
+    fn mul_multi_max_terms() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+
+        let terms = [(&p, &1); MAX_MULTI_TERMS];
+        assert_eq!(curve.mul(&p, &8), curve.mul_multi(&terms));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many terms for a multi-scalar multiplication")]
+    fn mul_multi_too_many_terms() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+
+        let _ = curve.mul_multi(&[(&p, &1); MAX_MULTI_TERMS + 1]);
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::{vec, vec::Vec};
+use modular::ModularArithmetic;
+use numeric::{FromBeSlice, ToBeSlice};
+
+use crate::{Curve, Numeric, Point};
+
+#[derive(Debug, PartialEq)]
+pub enum DecodingError {
+    InvalidLength,
+    InvalidTag,
+    NotOnCurve,
+}
+
+impl<T: Numeric + FromBeSlice + ToBeSlice, R: ModularArithmetic<T>> Curve<T, R> {
+    // Coordinates are encoded with the byte length of the field, e.g. 66 bytes for P-521
+    pub fn field_bytes(&self) -> usize {
+        self.ring.modulus().bit_length().div_ceil(8)
+    }
+
+    fn encode_coordinate(&self, n: &T, bytes: &mut Vec<u8>) {
+        let start = bytes.len();
+        bytes.resize(start + self.field_bytes(), 0);
+        n.to_be_slice(&mut bytes[start..])
+            .expect("coordinates are reduced modulo p");
+    }
+
+    fn decode_coordinate(&self, bytes: &[u8]) -> Result<T, DecodingError> {
+        let n = T::from_be_slice(bytes).ok_or(DecodingError::InvalidLength)?;
+
+        if n >= self.ring.modulus() {
+            return Err(DecodingError::NotOnCurve);
+        }
+
+        Ok(n)
+    }
+
+    pub fn encode_point(&self, p: &Point<T>, compressed: bool) -> Vec<u8> {
+        let (x, y) = match p {
+            Point::Identity => return vec![0x00],
+            Point::Affine { x, y } => (x, y),
+        };
+
+        let mut bytes = Vec::with_capacity(1 + 2 * self.field_bytes());
+
+        if compressed {
+            bytes.push(if y.bit(0) { 0x03 } else { 0x02 });
+            self.encode_coordinate(x, &mut bytes);
+        } else {
+            bytes.push(0x04);
+            self.encode_coordinate(x, &mut bytes);
+            self.encode_coordinate(y, &mut bytes);
+        }
+
+        bytes
+    }
+
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<Point<T>, DecodingError> {
+        let field_bytes = self.field_bytes();
+
+        let point = match bytes.first() {
+            None => return Err(DecodingError::InvalidLength),
+            Some(0x00) if bytes.len() == 1 => return Ok(Point::Identity),
+            Some(0x02 | 0x03) if bytes.len() == 1 + field_bytes => {
+                let x = self.decode_coordinate(&bytes[1..])?;
+                self.decompress(x, bytes[0] == 0x03)
+                    .ok_or(DecodingError::NotOnCurve)?
+            }
+            Some(0x04) if bytes.len() == 1 + 2 * field_bytes => {
+                let x = self.decode_coordinate(&bytes[1..1 + field_bytes])?;
+                let y = self.decode_coordinate(&bytes[1 + field_bytes..])?;
+                Point::new(x, y)
+            }
+            Some(0x00 | 0x02 | 0x03 | 0x04) => return Err(DecodingError::InvalidLength),
+            Some(_) => return Err(DecodingError::InvalidTag),
+        };
+
+        if !self.is_valid_point(&point) {
+            return Err(DecodingError::NotOnCurve);
+        }
+
+        Ok(point)
+    }
+
+    // Recovers the point with the given x coordinate and parity of y, if x is on the curve
+    pub fn decompress(&self, x: T, y_is_odd: bool) -> Option<Point<T>> {
+        let ring = &self.ring;
+        let x_repr = ring.to_repr(x);
+        let rhs = ring.add(
+            ring.add(
+                ring.mul(ring.mul(x_repr, x_repr), x_repr),
+                ring.mul(self.a, x_repr),
+            ),
+            self.b,
+        );
+
+        // negating is the same in every representation, so the root can be converted first
+        let y = ring.to_residue(ring.sqrt(rhs)?);
+        let y = if y.bit(0) == y_is_odd {
+            y
+        } else if y == T::zero() {
+            return None;
+        } else {
+            ring.sub(T::zero(), y)
+        };
+
+        Some(Point::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_bytes() {
+        assert_eq!(1, Curve::new(1, 1, 23).field_bytes());
+        assert_eq!(2, Curve::new(1, 1, 257).field_bytes());
+    }
+
+    #[test]
+    fn encode_point() {
+        let curve = Curve::new(1, 1, 23);
+
+        assert_eq!(
+            vec![0x04, 3, 10],
+            curve.encode_point(&Point::new(3, 10), false)
+        );
+        assert_eq!(vec![0x02, 3], curve.encode_point(&Point::new(3, 10), true));
+        assert_eq!(vec![0x03, 3], curve.encode_point(&Point::new(3, 13), true));
+        assert_eq!(vec![0x00], curve.encode_point(&Point::Identity, true));
+    }
+
+    #[test]
+    fn decode_point_round_trip() {
+        // 23 ≡ 3 (mod 4) and 13 ≡ 1 (mod 4) take different square root paths
+        for modulus in [23, 13] {
+            let curve = Curve::new(1, 1, modulus);
+
+            for x in 0..modulus {
+                for y in 0..modulus {
+                    let p = Point::new(x, y);
+                    if !curve.is_valid_point(&p) {
+                        continue;
+                    }
+
+                    assert_eq!(
+                        Ok(p.clone()),
+                        curve.decode_point(&curve.encode_point(&p, false))
+                    );
+                    assert_eq!(
+                        Ok(p.clone()),
+                        curve.decode_point(&curve.encode_point(&p, true))
+                    );
+                }
+            }
+
+            assert_eq!(Ok(Point::Identity), curve.decode_point(&[0x00]));
+        }
+    }
+
+    #[test]
+    fn decode_invalid_points() {
+        let curve = Curve::new(1, 1, 23);
+
+        assert_eq!(Err(DecodingError::InvalidLength), curve.decode_point(&[]));
+        assert_eq!(
+            Err(DecodingError::InvalidLength),
+            curve.decode_point(&[0x04, 3])
+        );
+        assert_eq!(
+            Err(DecodingError::InvalidLength),
+            curve.decode_point(&[0x02, 3, 10])
+        );
+        assert_eq!(
+            Err(DecodingError::InvalidTag),
+            curve.decode_point(&[0x05, 3])
+        );
+        assert_eq!(
+            Err(DecodingError::NotOnCurve),
+            curve.decode_point(&[0x04, 3, 11])
+        );
+        assert_eq!(
+            Err(DecodingError::NotOnCurve),
+            curve.decode_point(&[0x04, 26, 10])
+        );
+
+        // x = 2 gives y^2 = 11, which is not a square mod 23
+        assert_eq!(
+            Err(DecodingError::NotOnCurve),
+            curve.decode_point(&[0x02, 2])
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use alloc::vec::Vec;
+use modular::ModularArithmetic;
+use numeric::ConstantTimeEq;
+
+use crate::{Curve, JacobianPoint, Numeric, Point};
+
+const WINDOW: usize = 4;
+const MULTIPLES: usize = (1 << WINDOW) - 1;
+
+// Multiples j * 2^(4i) * base for every window i and every non-zero window value j, so multiplying
+// the base by a scalar becomes one addition per window with no doublings
+pub struct FixedBaseTable<T> {
+    base: Point<T>,
+    windows: Vec<[JacobianPoint<T>; MULTIPLES]>,
+}
+
+impl<T> FixedBaseTable<T> {
+    pub fn base(&self) -> &Point<T> {
+        &self.base
+    }
+}
+
+fn window<T: Numeric>(d: &T, index: usize) -> usize {
+    let size = core::mem::size_of::<T>() * 8;
+    let mut digit = 0;
+
+    for bit in (index * WINDOW..(index + 1) * WINDOW).rev() {
+        digit <<= 1;
+
+        if bit < size {
+            digit |= usize::from(d.bit(bit));
+        }
+    }
+
+    digit
+}
+
+impl<T: Numeric, R: ModularArithmetic<T>> Curve<T, R> {
+    // [p, 2p, ..., 15p]
+    fn multiples(&self, p: &JacobianPoint<T>) -> [JacobianPoint<T>; MULTIPLES] {
+        let mut multiples = [*p; MULTIPLES];
+
+        for i in 1..MULTIPLES {
+            multiples[i] = self.add_jacobian(&multiples[i - 1], p);
+        }
+
+        multiples
+    }
+
+    pub fn mul_windowed(&self, p: &Point<T>, d: &T) -> Point<T> {
+        let bits = d.bit_length();
+        let multiples = self.multiples(&self.to_jacobian(p));
+        let mut res = JacobianPoint::identity();
+
+        for i in (0..bits.div_ceil(WINDOW)).rev() {
+            for _ in 0..WINDOW {
+                res = self.double_jacobian(&res);
+            }
+
+            let digit = window(d, i);
+            if digit != 0 {
+                res = self.add_jacobian(&res, &multiples[digit - 1]);
+            }
+        }
+
+        self.to_affine(&res)
+    }
+
+    // Precomputes a table that covers scalars of up to `bits` bits, typically the bit length of the
+    // group order
+    pub fn precompute(&self, base: &Point<T>, bits: usize) -> FixedBaseTable<T> {
+        let count = bits.div_ceil(WINDOW);
+        let mut windows = Vec::with_capacity(count);
+        let mut window_base = self.to_jacobian(base);
+
+        for _ in 0..count {
+            let multiples = self.multiples(&window_base);
+            window_base = self.add_jacobian(&multiples[MULTIPLES - 1], &window_base);
+            windows.push(multiples);
+        }
+
+        FixedBaseTable {
+            base: base.clone(),
+            windows,
+        }
+    }
+
+    // Constant time in `d` as long as it fits in the table: every window costs one complete
+    // addition, of the identity for a zero digit, and the table entry is selected by scanning all of
+    // them rather than by indexing with the secret digit
+    pub fn mul_fixed(&self, table: &FixedBaseTable<T>, d: &T) -> Point<T> {
+        if d.bit_length() > table.windows.len() * WINDOW {
+            return self.mul(&table.base, d);
+        }
+
+        self.to_affine(&self.fixed_base(table, d))
+    }
+
+    fn fixed_base(&self, table: &FixedBaseTable<T>, d: &T) -> JacobianPoint<T> {
+        let mut res = JacobianPoint::identity();
+
+        for (i, multiples) in table.windows.iter().enumerate() {
+            let digit = window(d, i);
+            let mut entry = JacobianPoint::identity();
+
+            for (j, multiple) in multiples.iter().enumerate() {
+                let choice = (j + 1).ct_eq(&digit);
+                entry = JacobianPoint::conditional_select(&entry, multiple, choice);
+            }
+
+            res = self.add_jacobian_ct(&res, &entry);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::RecordingRing;
+
+    #[test]
+    fn window_digits() {
+        assert_eq!(0x3, window(&0x1234_5673u32, 0));
+        assert_eq!(0x7, window(&0x1234_5673u32, 1));
+        assert_eq!(0x1, window(&0x1234_5673u32, 7));
+        assert_eq!(0x0, window(&0x1234_5673u32, 8));
+    }
+
+    #[test]
+    fn mul_windowed_matches_mul() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+
+        for d in 0..300 {
+            assert_eq!(
+                curve.mul(&p, &d),
+                curve.mul_windowed(&p, &d),
+                "{} * {:?}",
+                d,
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn mul_fixed_matches_mul() {
+        let p = Point::new(11, 4);
+        let curve = Curve::new(-7, 10, 13);
+        let table = curve.precompute(&p, 5);
+
+        for d in 0..300 {
+            assert_eq!(
+                curve.mul(&p, &d),
+                curve.mul_fixed(&table, &d),
+                "{} * {:?}",
+                d,
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn mul_fixed_operations_do_not_depend_on_the_scalar() {
+        let curve = RecordingRing::curve(-7, 10, 13);
+        let table = curve.precompute(&Point::new(11, 4), 16);
+        let mut logs = Vec::new();
+
+        for d in [0, 1, 0x10, 0xffff, 0x8001, 0x1234] {
+            curve.ring.log.borrow_mut().clear();
+            curve.fixed_base(&table, &d);
+            logs.push(curve.ring.log.take());
+        }
+
+        assert!(!logs[0].is_empty());
+        assert!(logs.iter().all(|log| *log == logs[0]));
+    }
+}
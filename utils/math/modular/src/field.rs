@@ -0,0 +1,248 @@
+use core::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    ptr,
+};
+
+use numeric::{Bit, BitLength, Inv};
+
+use crate::{ModularArithmetic, Numeric};
+
+// An element of the integers modulo a prime, which borrows the ring doing its arithmetic so that
+// formulas can be written with operators. The value is kept in the ring's representation, which
+// every backend keeps fully reduced, so equal elements always compare equal. Both operands of an
+// operator must belong to the same ring.
+pub struct Fp<'a, T, R: ?Sized> {
+    value: T,
+    ring: &'a R,
+}
+
+impl<'a, T: Numeric, R: ModularArithmetic<T> + ?Sized> Fp<'a, T, R> {
+    pub fn new(ring: &'a R, a: T) -> Self {
+        Fp::from_repr(ring, ring.to_repr(a))
+    }
+
+    // For values already in the ring's representation, like the ones `to_repr` returns
+    pub fn from_repr(ring: &'a R, value: T) -> Self {
+        Fp { value, ring }
+    }
+
+    pub fn zero(ring: &'a R) -> Self {
+        Fp::new(ring, T::zero())
+    }
+
+    pub fn one(ring: &'a R) -> Self {
+        Fp::new(ring, T::one())
+    }
+
+    // The plain residue in 0..modulus
+    pub fn value(&self) -> T {
+        self.ring.to_residue(self.value)
+    }
+
+    pub fn to_repr(&self) -> T {
+        self.value
+    }
+
+    pub fn ring(&self) -> &'a R {
+        self.ring
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value == T::zero()
+    }
+
+    pub fn pow(&self, exp: T) -> Self
+    where
+        T: Bit + BitLength,
+    {
+        self.with(self.ring.pow(self.value, exp))
+    }
+
+    pub fn sqrt(&self) -> Option<Self>
+    where
+        T: Bit + BitLength,
+    {
+        self.ring.sqrt(self.value).map(|root| self.with(root))
+    }
+
+    fn with(&self, value: T) -> Self {
+        Fp::from_repr(self.ring, value)
+    }
+
+    fn check_ring(&self, other: &Self) {
+        debug_assert!(
+            ptr::eq(self.ring, other.ring),
+            "field elements belong to different rings"
+        );
+    }
+}
+
+impl<T: Copy, R: ?Sized> Clone for Fp<'_, T, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy, R: ?Sized> Copy for Fp<'_, T, R> {}
+
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> PartialEq for Fp<'_, T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.check_ring(other);
+        self.value == other.value
+    }
+}
+
+impl<T: Numeric + Eq, R: ModularArithmetic<T> + ?Sized> Eq for Fp<'_, T, R> {}
+
+impl<T: Numeric + fmt::Debug, R: ModularArithmetic<T> + ?Sized> fmt::Debug for Fp<'_, T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Fp").field(&self.value()).finish()
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> Add for Fp<'_, T, R> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        self.with(self.ring.add(self.value, rhs.value))
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> Sub for Fp<'_, T, R> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        self.with(self.ring.sub(self.value, rhs.value))
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> Mul for Fp<'_, T, R> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        self.with(self.ring.mul(self.value, rhs.value))
+    }
+}
+
+// Panics if the divisor has no inverse, which for a prime modulus only happens for zero
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> Div for Fp<'_, T, R> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.check_ring(&rhs);
+        let inv = rhs.inv().expect("divisor has no inverse");
+        self.with(self.ring.mul(self.value, inv.value))
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> Neg for Fp<'_, T, R> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.with(self.ring.sub(T::zero(), self.value))
+    }
+}
+
+impl<T: Numeric, R: ModularArithmetic<T> + ?Sized> Inv for Fp<'_, T, R> {
+    fn inv(&self) -> Option<Self> {
+        self.ring.inv(self.value).map(|value| self.with(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use big_num::types::U256;
+
+    use crate::{MontgomeryRing, Ring};
+
+    use super::*;
+
+    #[test]
+    fn operators_match_the_ring() {
+        let ring = Ring::new(13);
+
+        for a in 0..13 {
+            for b in 0..13 {
+                let (x, y) = (Fp::new(&ring, a), Fp::new(&ring, b));
+
+                assert_eq!(ring.add(a, b), (x + y).value(), "{} + {}", a, b);
+                assert_eq!(ring.sub(a, b), (x - y).value(), "{} - {}", a, b);
+                assert_eq!(ring.mul(a, b), (x * y).value(), "{} * {}", a, b);
+                if b != 0 {
+                    assert_eq!(x, x / y * y, "{} / {}", a, b);
+                }
+            }
+
+            assert_eq!(Fp::zero(&ring), Fp::new(&ring, a) + -Fp::new(&ring, a));
+        }
+    }
+
+    #[test]
+    fn canonical_reduction() {
+        let ring = Ring::new(7);
+
+        assert_eq!(Fp::new(&ring, 3), Fp::new(&ring, 10));
+        assert_eq!(Fp::new(&ring, 3), Fp::new(&ring, -4));
+        assert_eq!(6, Fp::new(&ring, -1).value());
+        assert!(Fp::new(&ring, 14).is_zero());
+    }
+
+    #[test]
+    fn inverse() {
+        let ring = Ring::new(13);
+
+        assert_eq!(None, Fp::zero(&ring).inv());
+        for a in 1..13 {
+            let x = Fp::new(&ring, a);
+            assert_eq!(Fp::one(&ring), x * x.inv().unwrap(), "{}^-1", a);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor has no inverse")]
+    fn division_by_zero() {
+        let ring = Ring::new(13);
+        let _ = Fp::one(&ring) / Fp::zero(&ring);
+    }
+
+    #[test]
+    fn pow_and_sqrt() {
+        let ring = Ring::new(23);
+        let x = Fp::new(&ring, 5);
+
+        assert_eq!(Fp::one(&ring), x.pow(22));
+        assert_eq!(x * x * x, x.pow(3));
+
+        let square = x * x;
+        let root = square.sqrt().unwrap();
+        assert_eq!(square, root * root);
+        assert_eq!(None, Fp::new(&ring, 5).sqrt());
+    }
+
+    #[test]
+    fn montgomery_backend() {
+        // the P-256 prime
+        let p = U256::from_be_hex_const(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        );
+        let ring = MontgomeryRing::new(p);
+        let plain = Ring::new(p);
+
+        let a = U256::from_be_hex_const(
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        );
+        let b = U256::from_be_hex_const(
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        );
+        let (x, y) = (Fp::new(&ring, a), Fp::new(&ring, b));
+
+        assert_eq!(plain.mul(a, b), (x * y).value());
+        assert_eq!(plain.sub(a, b), (x - y).value());
+        assert_eq!(a, (x / y * y).value());
+        assert_eq!(b, Fp::from_repr(&ring, y.to_repr()).value());
+    }
+}
@@ -1,11 +1,24 @@
 #![no_std]
 
-use core::{
-    mem::swap,
-    ops::{Mul, Rem},
+mod field;
+mod montgomery;
+mod pow;
+mod reduction;
+
+pub use field::Fp;
+pub use montgomery::MontgomeryRing;
+pub use reduction::{
+    BarrettReducer, DivisionReducer, P256Reducer, P384Reducer, P521Reducer, Reducer,
 };
 
-use numeric::{CheckedAdd, CheckedMul, CheckedSub, Narrow, One, RemEuclid, Widen, Zero};
+use core::ops::Neg;
+
+use big_num::{BigInt, BigUint};
+
+use numeric::{
+    Bit, BitLength, CheckedAdd, CheckedMul, CheckedSub, ConditionallySelectable, Narrow, One,
+    RemEuclid, Widen, Zero,
+};
 
 pub trait Numeric:
     core::ops::Div<Output = Self>
@@ -42,14 +55,246 @@ impl<T> Numeric for T where
 {
 }
 
-pub trait Narrowed: Numeric + Widen {}
-impl<T> Narrowed for T where T: Numeric + Widen {}
+pub trait Narrowed: Numeric + Widen + ToSigned {}
+impl<T> Narrowed for T where T: Numeric + Widen + ToSigned {}
+
+// Pairs an integer type with a signed type holding all of its values and their negations, which
+// `Ring::inv` runs the extended Euclidean algorithm in since the Bézout coefficients can be negative
+pub trait ToSigned: Sized {
+    type Signed: Numeric + Neg<Output = Self::Signed>;
+
+    fn to_signed(&self) -> Self::Signed;
+
+    // Expects a non-negative value that fits in `Self`
+    fn from_signed(value: &Self::Signed) -> Self;
+}
+
+macro_rules! impl_to_signed {
+    ($($t:ty => $s:ty),*) => {
+        $(
+            impl ToSigned for $t {
+                type Signed = $s;
+
+                fn to_signed(&self) -> Self::Signed {
+                    *self as $s
+                }
+
+                fn from_signed(value: &Self::Signed) -> Self {
+                    *value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_to_signed! {
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    i128 => i128,
+    u8 => i16,
+    u16 => i32,
+    u32 => i64,
+    u64 => i128
+}
+
+impl<const N: usize> ToSigned for BigUint<N> {
+    type Signed = BigInt<N>;
+
+    fn to_signed(&self) -> Self::Signed {
+        BigInt::from(*self)
+    }
+
+    fn from_signed(value: &Self::Signed) -> Self {
+        value.magnitude()
+    }
+}
 
 pub trait Widened<T>: Numeric + Narrow<Output = T> {}
 impl<T, U> Widened<U> for T where T: Numeric + Narrow<Output = U> {}
 
-pub struct Ring<T> {
+// Arithmetic modulo a fixed modulus, shared by `Ring` and faster backends such as `MontgomeryRing`.
+// Elements may be kept in a backend specific representation: `to_repr` and `to_residue` convert
+// from and to plain residues, every other method takes and returns representations. Exponents are
+// always plain integers.
+pub trait ModularArithmetic<T: Numeric> {
+    fn modulus(&self) -> T;
+    fn to_repr(&self, a: T) -> T;
+    fn to_residue(&self, a: T) -> T;
+    fn add(&self, a: T, b: T) -> T;
+    fn sub(&self, a: T, b: T) -> T;
+    fn mul(&self, a: T, b: T) -> T;
+    fn inv(&self, a: T) -> Option<T>;
+
+    fn pow(&self, base: T, exp: T) -> T
+    where
+        T: Bit + BitLength,
+    {
+        pow::sliding_window(self, base, exp)
+    }
+
+    // Slower than `pow`, but its running time does not depend on the exponent, for secret ones
+    fn pow_ct(&self, base: T, exp: T) -> T
+    where
+        T: Bit + ConditionallySelectable,
+    {
+        pow::fixed_window_ct(self, base, exp)
+    }
+
+    // Euler's criterion: 1 if a is a non-zero square, -1 if it is not, and 0 if a ≡ 0. The modulus
+    // must be an odd prime
+    fn legendre(&self, a: T) -> i8
+    where
+        T: Bit + BitLength,
+    {
+        let one = T::one();
+        let two = one + one;
+
+        let symbol = self.pow(a, (self.modulus() - one) / two);
+
+        if symbol == T::zero() {
+            0
+        } else if symbol == self.to_repr(one) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    // The modulus must be prime. Returns one of the two roots, the other one is p - root. Like
+    // every other method, it takes and returns representations, so backends other than `Ring`
+    // expect `a` to be reduced already.
+    fn sqrt(&self, a: T) -> Option<T>
+    where
+        T: Bit + BitLength,
+    {
+        square_root(self, a)
+    }
+}
+
+fn square_root<T, R>(ring: &R, a: T) -> Option<T>
+where
+    T: Numeric + Bit + BitLength,
+    R: ModularArithmetic<T> + ?Sized,
+{
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+    let four = two + two;
+
+    let modulus = ring.modulus();
+
+    if a == T::zero() || modulus == two {
+        return Some(a);
+    }
+
+    // for p ≡ 3 (mod 4), a^((p + 1) / 4) is a root whenever one exists
+    if modulus % four == three {
+        let root = ring.pow(a, modulus / four + one);
+
+        return if ring.mul(root, root) == a {
+            Some(root)
+        } else {
+            None
+        };
+    }
+
+    if ring.legendre(a) != 1 {
+        return None;
+    }
+
+    tonelli_shanks(ring, a)
+}
+
+// Expects a to be a non-zero quadratic residue
+fn tonelli_shanks<T, R>(ring: &R, a: T) -> Option<T>
+where
+    T: Numeric + Bit + BitLength,
+    R: ModularArithmetic<T> + ?Sized,
+{
+    let one = T::one();
+    let two = one + one;
+    let repr_one = ring.to_repr(one);
+
+    // p - 1 = q * 2^s with q odd
+    let mut q = ring.modulus() - one;
+    let mut s = 0;
+    while q % two == T::zero() {
+        q = q / two;
+        s += 1;
+    }
+
+    // a prime modulus always has a non-residue below it, but a composite one may have none
+    let mut candidate = two;
+    let z = loop {
+        if candidate >= ring.modulus() {
+            return None;
+        }
+
+        let z = ring.to_repr(candidate);
+        if ring.legendre(z) == -1 {
+            break z;
+        }
+        candidate = candidate + one;
+    };
+
+    let mut m = s;
+    let mut c = ring.pow(z, q);
+    let mut t = ring.pow(a, q);
+    let mut root = ring.pow(a, (q + one) / two);
+
+    while t != repr_one {
+        // least i such that t^(2^i) = 1
+        let mut i = 0;
+        let mut t2i = t;
+        while t2i != repr_one {
+            t2i = ring.mul(t2i, t2i);
+            i += 1;
+
+            if i == m {
+                return None;
+            }
+        }
+
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = ring.mul(b, b);
+        }
+
+        m = i;
+        c = ring.mul(b, b);
+        t = ring.mul(t, c);
+        root = ring.mul(root, b);
+    }
+
+    Some(root)
+}
+
+// Returns (g, x, y) with a * x + b * y = g and g = gcd(a, b) >= 0. Needs a signed type since the
+// Bézout coefficients can be negative, e.g. BigInt<N> or the primitive signed integers.
+pub fn extended_gcd<T: Numeric + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (T::one(), T::zero());
+    let (mut t0, mut t1) = (T::zero(), T::one());
+
+    while r1 != T::zero() {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+
+    if r0 < T::zero() {
+        (-r0, -s0, -t0)
+    } else {
+        (r0, s0, t0)
+    }
+}
+
+pub struct Ring<T, R = DivisionReducer<T>> {
     modulus: T,
+    reducer: R,
 }
 
 impl<T: Narrowed> Ring<T>
@@ -57,55 +302,42 @@ where
     <T as Widen>::Output: Widened<T>,
 {
     pub fn new(modulus: T) -> Self {
-        Ring { modulus }
-    }
-
-    fn extended_euclidean(&self, a: T, b: T) -> (T, T, T) {
-        let mut r0 = a.widen();
-        let mut r1 = b.widen();
-        let mut s0 = T::one().widen();
-        let mut s1 = T::zero().widen();
-        let mut t0 = T::zero().widen();
-        let mut t1 = T::one().widen();
-        let mut n = 0;
-        while r1 > T::zero().widen() {
-            let q = r0 / r1;
-            r0 = if r0 > q * r1 {
-                r0 - q * r1
-            } else {
-                q * r1 - r0
-            };
-            swap(&mut r0, &mut r1);
-            s0 = s0 + q * s1;
-            swap(&mut s0, &mut s1);
-            t0 = t0 + q * t1;
-            swap(&mut t0, &mut t1);
-            n += 1;
+        Ring::with_reducer(modulus)
+    }
+}
+
+impl<T: Narrowed, R: Reducer<T>> Ring<T, R>
+where
+    <T as Widen>::Output: Widened<T>,
+{
+    pub fn with_reducer(modulus: T) -> Self {
+        Ring {
+            modulus,
+            reducer: R::new(modulus),
         }
+    }
 
-        if (n % 2) != 0 {
-            s0 = b.widen() - s0
-        } else {
-            t0 = a.widen() - t0
-        };
+    pub fn modulus(&self) -> T {
+        self.modulus
+    }
 
-        (r0.narrow(), s0.narrow(), t0.narrow())
+    fn extended_euclidean(&self, a: T, b: T) -> (T::Signed, T::Signed, T::Signed) {
+        extended_gcd(a.to_signed(), b.to_signed())
     }
 
     pub fn inv(&self, a: T) -> Option<T> {
+        let a = a.rem_euclid(&self.modulus);
         let (gcd, x, _) = self.extended_euclidean(a, self.modulus);
 
-        if gcd != T::one() {
+        if gcd != T::Signed::one() {
             return None;
         }
 
-        let inv = (x.widen() % self.modulus.widen() + self.modulus.widen()) % self.modulus.widen();
-        Some(inv.narrow())
+        Some(T::from_signed(&x.rem_euclid(&self.modulus.to_signed())))
     }
 
     pub fn add(&self, a: T, b: T) -> T {
-        let sum = a.widen() + b.widen();
-        (sum.rem_euclid(&self.modulus.widen())).narrow()
+        self.reducer.reduce(a.widen() + b.widen())
     }
 
     pub fn sub(&self, a: T, b: T) -> T {
@@ -121,18 +353,70 @@ where
     }
 
     pub fn mul(&self, a: T, b: T) -> T {
-        let product = a.widen() * b.widen();
-        (product.rem_euclid(&self.modulus.widen())).narrow()
+        self.reducer.reduce(a.widen() * b.widen())
+    }
+}
+
+// Ring elements are plain residues, so the conversions only reduce
+impl<T: Narrowed, R: Reducer<T>> ModularArithmetic<T> for Ring<T, R>
+where
+    <T as Widen>::Output: Widened<T>,
+{
+    fn modulus(&self) -> T {
+        self.modulus
+    }
+
+    fn to_repr(&self, a: T) -> T {
+        a.rem_euclid(&self.modulus)
+    }
+
+    fn to_residue(&self, a: T) -> T {
+        a
+    }
+
+    fn add(&self, a: T, b: T) -> T {
+        Ring::add(self, a, b)
+    }
+
+    fn sub(&self, a: T, b: T) -> T {
+        Ring::sub(self, a, b)
+    }
+
+    fn mul(&self, a: T, b: T) -> T {
+        Ring::mul(self, a, b)
+    }
+
+    fn inv(&self, a: T) -> Option<T> {
+        Ring::inv(self, a)
+    }
+
+    // Ring elements are plain integers, so unreduced inputs are accepted as for the other methods
+    fn sqrt(&self, a: T) -> Option<T>
+    where
+        T: Bit + BitLength,
+    {
+        square_root(self, self.to_repr(a))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use big_num::types::U256;
+    use numeric::{FromStrRadix, ToStrRadix};
+
     use super::*;
 
+    // xorshift, so the tests cover many values without extra dependencies
+    pub(crate) fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
     #[test]
     fn extended_euclidean_common_cases() {
-        let ring = Ring { modulus: 13 };
+        let ring = Ring::new(13);
 
         assert_eq!(ring.extended_euclidean(101, 13), (1, 4, -31));
         assert_eq!(ring.extended_euclidean(123, 19), (1, -2, 13));
@@ -143,9 +427,52 @@ mod tests {
         assert_eq!(ring.extended_euclidean(50, 70), (10, 3, -2));
     }
 
+    #[test]
+    fn extended_euclidean_unsigned() {
+        let ring = Ring::new(13u32);
+        assert_eq!(ring.extended_euclidean(101, 13), (1i64, 4, -31));
+
+        let modulus = U256::from(1_000_000_007u32);
+        let ring = Ring::new(modulus);
+        let (gcd, x, y) = ring.extended_euclidean(U256::from(123_456_789u32), modulus);
+
+        assert_eq!(BigInt::one(), gcd);
+        assert_eq!(
+            gcd,
+            BigInt::from(U256::from(123_456_789u32)) * x + BigInt::from(modulus) * y
+        );
+        assert_eq!(
+            Some(U256::from(18_633_540u32)),
+            ring.inv(U256::from(123_456_789u32))
+        );
+    }
+
+    #[test]
+    fn extended_gcd_common_cases() {
+        assert_eq!(extended_gcd(101, 13), (1, 4, -31));
+        assert_eq!(extended_gcd(123, 19), (1, -2, 13));
+        assert_eq!(extended_gcd(25, 36), (1, 13, -9));
+        assert_eq!(extended_gcd(69, 54), (3, -7, 9));
+        assert_eq!(extended_gcd(55, 79), (1, 23, -16));
+        assert_eq!(extended_gcd(33, 44), (11, -1, 1));
+        assert_eq!(extended_gcd(50, 70), (10, 3, -2));
+        assert_eq!(extended_gcd(-50, 70), (10, -3, -2));
+        assert_eq!(extended_gcd(0, -7), (7, 0, -1));
+    }
+
+    #[test]
+    fn extended_gcd_big_int() {
+        let a = BigInt::<8>::from_str_radix("-123456789012345678901234567890", 10).unwrap();
+        let b = BigInt::<8>::from_str_radix("987654321098765432109876543210", 10).unwrap();
+        let (g, x, y) = extended_gcd(a, b);
+
+        assert_eq!("9000000000900000000090", g.to_str_radix(10));
+        assert_eq!(g, a * x + b * y);
+    }
+
     #[test]
     fn mod_inv_common_cases() {
-        let ring = Ring { modulus: 13 };
+        let ring = Ring::new(13);
 
         assert_eq!(ring.inv(2), Some(7));
         assert_eq!(ring.inv(4), Some(10));
@@ -156,24 +483,24 @@ mod tests {
         assert_eq!(ring.inv(13), None);
         assert_eq!(ring.inv(0), None);
 
-        let ring = Ring { modulus: 26 };
+        let ring = Ring::new(26);
         assert_eq!(ring.inv(7), Some(15));
         assert_eq!(ring.inv(12), None);
     }
 
     #[test]
     fn mod_inv_edge_cases() {
-        let ring = Ring { modulus: 17 };
+        let ring = Ring::new(17);
 
         assert_eq!(ring.inv(1), Some(1));
         assert_eq!(ring.inv(16), Some(16));
 
-        let ring = Ring { modulus: 20 };
+        let ring = Ring::new(20);
 
         assert_eq!(ring.inv(3), Some(7));
         assert_eq!(ring.inv(15), None);
 
-        let ring = Ring { modulus: 8 };
+        let ring = Ring::new(8);
 
         assert_eq!(ring.inv(1), Some(1));
         assert_eq!(ring.inv(3), Some(3));
@@ -182,7 +509,7 @@ mod tests {
 
     #[test]
     fn addition_common_cases() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         // Test cases for addition within modulus
         assert_eq!(ring.add(2, 3), 5);
@@ -196,7 +523,7 @@ mod tests {
 
     #[test]
     fn addition_at_num_max() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         assert_eq!(ring.add(i8::MIN, i8::MIN), 3);
         assert_eq!(ring.add(i8::MAX, i8::MAX), 2);
@@ -204,7 +531,7 @@ mod tests {
 
     #[test]
     fn subtraction_common_cases() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         // Test cases for subtraction within modulus
         assert_eq!(ring.sub(4, 2), 2);
@@ -218,7 +545,7 @@ mod tests {
 
     #[test]
     fn subtraction_at_num_max() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         assert_eq!(ring.sub(i32::MIN, i32::MAX), 4);
         assert_eq!(ring.sub(i32::MAX, i32::MIN), 3);
@@ -226,7 +553,7 @@ mod tests {
 
     #[test]
     fn multiplication_common_cases() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         // Test cases for multiplication within modulus
         assert_eq!(ring.mul(2, 3), 6);
@@ -241,21 +568,157 @@ mod tests {
 
     #[test]
     fn multiplication_edge_cases() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         assert_eq!(ring.mul(0, 0), 0);
         assert_eq!(ring.mul(0, 1), 0);
         assert_eq!(ring.mul(1, 0), 0);
 
-        let ring = Ring { modulus: 13 };
+        let ring = Ring::new(13);
 
         assert_eq!(ring.mul(12, 12), 1);
         assert_eq!(ring.mul(6, 11), 1);
     }
 
+    #[test]
+    fn pow_common_cases() {
+        let ring = Ring::new(13);
+
+        assert_eq!(ring.pow(2, 0), 1);
+        assert_eq!(ring.pow(2, 1), 2);
+        assert_eq!(ring.pow(2, 10), 10);
+        assert_eq!(ring.pow(7, 12), 1);
+        assert_eq!(ring.pow(0, 5), 0);
+
+        let ring = Ring::new(1);
+        assert_eq!(ring.pow(5, 0), 0);
+    }
+
+    #[test]
+    fn sqrt_three_mod_four() {
+        let ring = Ring::new(23);
+
+        for a in 0..23 {
+            match ring.sqrt(a) {
+                Some(root) => assert_eq!(ring.mul(root, root), a),
+                None => assert!((0..23).all(|x| ring.mul(x, x) != a)),
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_unreduced_input() {
+        let ring = Ring::new(23);
+        let root = ring.sqrt(23 + 4).unwrap();
+        assert_eq!(4, ring.mul(root, root));
+
+        let ring = Ring::new(13);
+        let root = ring.sqrt(13 + 3).unwrap();
+        assert_eq!(3, ring.mul(root, root));
+        let root = ring.sqrt(-1).unwrap();
+        assert_eq!(12, ring.mul(root, root));
+        assert_eq!(Some(0), ring.sqrt(26));
+    }
+
+    #[test]
+    fn sqrt_tonelli_shanks() {
+        for p in [13, 17, 41, 97, 257] {
+            let ring = Ring::new(p);
+
+            for a in 0..p {
+                match ring.sqrt(a) {
+                    Some(root) => assert_eq!(ring.mul(root, root), a, "sqrt({}) mod {}", a, p),
+                    None => assert!((0..p).all(|x| ring.mul(x, x) != a), "sqrt({}) mod {}", a, p),
+                }
+            }
+        }
+
+        // 998244353 - 1 = 119 * 2^23, and 3 generates the whole multiplicative group
+        let ring = Ring::new(998_244_353u64);
+        for a in [2, 4, 7, 123_456_789, 998_244_352] {
+            let root = ring.sqrt(a).unwrap();
+            assert_eq!(ring.mul(root, root), a);
+        }
+        assert_eq!(ring.sqrt(3), None);
+        assert_eq!(ring.sqrt(5), None);
+    }
+
+    // Reports every element as a square, as a composite modulus without a non-residue would
+    struct NoNonResidues(Ring<i32>);
+
+    impl ModularArithmetic<i32> for NoNonResidues {
+        fn modulus(&self) -> i32 {
+            self.0.modulus()
+        }
+
+        fn to_repr(&self, a: i32) -> i32 {
+            self.0.to_repr(a)
+        }
+
+        fn to_residue(&self, a: i32) -> i32 {
+            a
+        }
+
+        fn add(&self, a: i32, b: i32) -> i32 {
+            self.0.add(a, b)
+        }
+
+        fn sub(&self, a: i32, b: i32) -> i32 {
+            self.0.sub(a, b)
+        }
+
+        fn mul(&self, a: i32, b: i32) -> i32 {
+            self.0.mul(a, b)
+        }
+
+        fn inv(&self, a: i32) -> Option<i32> {
+            self.0.inv(a)
+        }
+
+        fn legendre(&self, _: i32) -> i8 {
+            1
+        }
+    }
+
+    #[test]
+    fn sqrt_without_non_residue_terminates() {
+        assert_eq!(None, NoNonResidues(Ring::new(13)).sqrt(4));
+    }
+
+    #[test]
+    fn sqrt_composite_modulus() {
+        // moduli that are 1 mod 4, so every residue goes through Tonelli-Shanks
+        for n in [45, 65, 561, 1729] {
+            let ring = Ring::new(n);
+
+            for a in 0..n {
+                if let Some(root) = ring.sqrt(a) {
+                    assert_eq!(a, ring.mul(root, root), "sqrt({}) mod {}", a, n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn legendre_common_cases() {
+        let ring = Ring::new(13);
+
+        assert_eq!(ring.legendre(0), 0);
+        assert_eq!(ring.legendre(13), 0);
+        assert_eq!(ring.legendre(1), 1);
+        assert_eq!(ring.legendre(4), 1);
+        assert_eq!(ring.legendre(10), 1);
+        assert_eq!(ring.legendre(2), -1);
+        assert_eq!(ring.legendre(5), -1);
+
+        let ring = Ring::new(23);
+        let squares = (1..23).filter(|&a| ring.legendre(a) == 1).count();
+        assert_eq!(squares, 11);
+    }
+
     #[test]
     fn multiplication_at_num_max() {
-        let ring = Ring { modulus: 7 };
+        let ring = Ring::new(7);
 
         assert_eq!(ring.mul(i8::MAX, i8::MAX), 1);
         assert_eq!(ring.mul(i8::MAX, i8::MIN), 5);
@@ -0,0 +1,224 @@
+use big_num::{BigUint, DoubleWord, Word};
+use numeric::{One, OverflowingAdd, OverflowingSub, RemEuclid, WrappingAdd, Zero};
+
+use crate::ModularArithmetic;
+
+// Elements are kept as a * R mod m with R = 2^(wN) for w-bit limbs, so a product only needs one Montgomery
+// reduction (REDC) instead of a long division by the modulus. The modulus must be odd.
+pub struct MontgomeryRing<const N: usize> {
+    modulus: BigUint<N>,
+    // -m^-1 mod 2^w
+    m_prime: Word,
+    // R^2 mod m, used to convert into Montgomery form
+    r2: BigUint<N>,
+}
+
+impl<const N: usize> MontgomeryRing<N> {
+    pub fn new(modulus: BigUint<N>) -> Self {
+        let m = modulus.to_limbs();
+        assert!(m[0] & 1 == 1, "Montgomery modulus must be odd");
+
+        // Newton's iteration doubles the number of correct low bits on every step, starting from
+        // the single bit of 1
+        let mut inv: Word = 1;
+        for _ in 0..Word::BITS.trailing_zeros() {
+            inv = inv.wrapping_mul((2 as Word).wrapping_sub(m[0].wrapping_mul(inv)));
+        }
+
+        let mut ring = MontgomeryRing {
+            modulus,
+            m_prime: inv.wrapping_neg(),
+            r2: BigUint::zero(),
+        };
+
+        // 2^(2wN) mod m by repeated doubling, which avoids a double width division
+        let mut r2 = BigUint::one().rem_euclid(&modulus);
+        for _ in 0..2 * Word::BITS as usize * N {
+            r2 = ring.add(r2, r2);
+        }
+        ring.r2 = r2;
+
+        ring
+    }
+
+    // Computes a * b * R^-1 mod m with the coarsely integrated operand scanning (CIOS) method
+    fn redc_mul(&self, a: &BigUint<N>, b: &BigUint<N>) -> BigUint<N> {
+        let a = a.to_limbs();
+        let b = b.to_limbs();
+        let m = self.modulus.to_limbs();
+
+        let mut t: [Word; N] = [0; N];
+        let mut t_hi: DoubleWord = 0;
+
+        for &b_i in b.iter() {
+            let mut carry: DoubleWord = 0;
+            for j in 0..N {
+                let cur =
+                    DoubleWord::from(t[j]) + DoubleWord::from(a[j]) * DoubleWord::from(b_i) + carry;
+                t[j] = cur as Word;
+                carry = cur >> Word::BITS;
+            }
+            let top = t_hi + carry;
+
+            let q = t[0].wrapping_mul(self.m_prime);
+            let cur = DoubleWord::from(t[0]) + DoubleWord::from(q) * DoubleWord::from(m[0]);
+            let mut carry = cur >> Word::BITS;
+            for j in 1..N {
+                let cur =
+                    DoubleWord::from(t[j]) + DoubleWord::from(q) * DoubleWord::from(m[j]) + carry;
+                t[j - 1] = cur as Word;
+                carry = cur >> Word::BITS;
+            }
+            let top = top + carry;
+            t[N - 1] = top as Word;
+            t_hi = top >> Word::BITS;
+        }
+
+        // t < 2m, so at most one subtraction brings it into range
+        let t = BigUint::new(t);
+        let (reduced, borrow) = t.overflowing_sub(&self.modulus);
+        if t_hi != 0 || !borrow {
+            reduced
+        } else {
+            t
+        }
+    }
+}
+
+impl<const N: usize> ModularArithmetic<BigUint<N>> for MontgomeryRing<N> {
+    fn modulus(&self) -> BigUint<N> {
+        self.modulus
+    }
+
+    fn to_repr(&self, a: BigUint<N>) -> BigUint<N> {
+        self.redc_mul(&a.rem_euclid(&self.modulus), &self.r2)
+    }
+
+    fn to_residue(&self, a: BigUint<N>) -> BigUint<N> {
+        self.redc_mul(&a, &BigUint::one())
+    }
+
+    fn add(&self, a: BigUint<N>, b: BigUint<N>) -> BigUint<N> {
+        let (sum, carry) = a.overflowing_add(&b);
+        let (reduced, borrow) = sum.overflowing_sub(&self.modulus);
+
+        if carry || !borrow {
+            reduced
+        } else {
+            sum
+        }
+    }
+
+    fn sub(&self, a: BigUint<N>, b: BigUint<N>) -> BigUint<N> {
+        let (difference, borrow) = a.overflowing_sub(&b);
+
+        if borrow {
+            difference.wrapping_add(&self.modulus)
+        } else {
+            difference
+        }
+    }
+
+    fn mul(&self, a: BigUint<N>, b: BigUint<N>) -> BigUint<N> {
+        self.redc_mul(&a, &b)
+    }
+
+    // Inverts with Fermat's little theorem, so inverses are only found when the modulus is prime
+    fn inv(&self, a: BigUint<N>) -> Option<BigUint<N>> {
+        let two = BigUint::from(2u8);
+        let inv = self.pow(a, self.modulus - two);
+
+        if self.mul(inv, a) == self.to_repr(BigUint::one()) {
+            Some(inv)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use big_num::types::{U192, U256};
+
+    use super::*;
+    use crate::Ring;
+
+    #[test]
+    fn repr_round_trip() {
+        let ring = MontgomeryRing::new(U192::from(97u8));
+
+        for a in 0..97u8 {
+            let a = U192::from(a);
+            assert_eq!(a, ring.to_residue(ring.to_repr(a)));
+        }
+    }
+
+    #[test]
+    fn matches_ring() {
+        let modulus =
+            U256::from_be_hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF")
+                .unwrap();
+        let ring = Ring::new(modulus);
+        let montgomery = MontgomeryRing::new(modulus);
+
+        let values = [
+            U256::zero(),
+            U256::one(),
+            U256::from(2u8),
+            modulus - U256::one(),
+            U256::from_be_hex("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296")
+                .unwrap(),
+            U256::from_be_hex("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5")
+                .unwrap(),
+        ];
+
+        for a in values {
+            let a_repr = montgomery.to_repr(a);
+
+            for b in values {
+                let b_repr = montgomery.to_repr(b);
+
+                assert_eq!(
+                    ring.add(a, b),
+                    montgomery.to_residue(montgomery.add(a_repr, b_repr))
+                );
+                assert_eq!(
+                    ring.sub(a, b),
+                    montgomery.to_residue(montgomery.sub(a_repr, b_repr))
+                );
+                assert_eq!(
+                    ring.mul(a, b),
+                    montgomery.to_residue(montgomery.mul(a_repr, b_repr))
+                );
+            }
+
+            assert_eq!(
+                ring.inv(a),
+                montgomery.inv(a_repr).map(|inv| montgomery.to_residue(inv))
+            );
+        }
+    }
+
+    #[test]
+    fn sqrt_in_montgomery_form() {
+        // 97 ≡ 1 (mod 4) goes through Tonelli-Shanks
+        let ring = MontgomeryRing::new(U192::from(97u8));
+
+        for a in 0..97u8 {
+            let a = ring.to_repr(U192::from(a));
+
+            if let Some(root) = ring.sqrt(a) {
+                assert_eq!(a, ring.mul(root, root));
+            } else {
+                assert_eq!(-1, ring.legendre(a));
+            }
+        }
+    }
+
+    #[test]
+    fn inv_composite_modulus() {
+        let ring = MontgomeryRing::new(U192::from(15u8));
+
+        assert_eq!(None, ring.inv(ring.to_repr(U192::from(5u8))));
+    }
+}
@@ -0,0 +1,209 @@
+use numeric::{Bit, BitLength, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{ModularArithmetic, Numeric};
+
+// Odd powers needed for the largest window of 6 bits
+const MAX_ODD_POWERS: usize = 1 << 5;
+
+// Bits of the exponent consumed per multiplication by the constant time variant
+const CT_WINDOW: usize = 4;
+
+// Window sizes by exponent length, as used by OpenSSL. Longer windows save multiplications but
+// cost a bigger table of odd powers up front.
+fn window_size(bits: usize) -> usize {
+    match bits {
+        672.. => 6,
+        240..=671 => 5,
+        80..=239 => 4,
+        24..=79 => 3,
+        _ => 1,
+    }
+}
+
+// Left-to-right sliding window exponentiation. Every window starts and ends with a one bit, so it
+// is a single multiplication by an odd power from the table, and zeros between windows only cost
+// a squaring.
+pub(crate) fn sliding_window<T, R>(ring: &R, base: T, exp: T) -> T
+where
+    T: Numeric + Bit + BitLength,
+    R: ModularArithmetic<T> + ?Sized,
+{
+    let bits = exp.bit_length();
+    let window = window_size(bits);
+
+    // odd_powers[i] = base^(2i + 1)
+    let mut odd_powers = [base; MAX_ODD_POWERS];
+    if window > 1 {
+        let square = ring.mul(base, base);
+        for i in 1..1 << (window - 1) {
+            odd_powers[i] = ring.mul(odd_powers[i - 1], square);
+        }
+    }
+
+    let mut res = ring.to_repr(T::one());
+    let mut i = bits;
+
+    while i > 0 {
+        if !exp.bit(i - 1) {
+            res = ring.mul(res, res);
+            i -= 1;
+            continue;
+        }
+
+        // the window covers bits i - 1 down to low, which is the lowest one bit in reach
+        let mut low = i.saturating_sub(window);
+        while !exp.bit(low) {
+            low += 1;
+        }
+
+        let mut value = 0;
+        for j in (low..i).rev() {
+            res = ring.mul(res, res);
+            value = (value << 1) | usize::from(exp.bit(j));
+        }
+        res = ring.mul(res, odd_powers[value >> 1]);
+        i = low;
+    }
+
+    res
+}
+
+// Fixed window exponentiation over every bit of T, so the sequence of squarings and
+// multiplications does not depend on the exponent, and the table is read by selecting over all of
+// its entries. It is only as constant time as the ring's own arithmetic.
+pub(crate) fn fixed_window_ct<T, R>(ring: &R, base: T, exp: T) -> T
+where
+    T: Numeric + Bit + ConditionallySelectable,
+    R: ModularArithmetic<T> + ?Sized,
+{
+    let bits = core::mem::size_of::<T>() * 8;
+
+    // powers[i] = base^i
+    let mut powers = [ring.to_repr(T::one()); 1 << CT_WINDOW];
+    for i in 1..powers.len() {
+        powers[i] = ring.mul(powers[i - 1], base);
+    }
+
+    let mut res = powers[0];
+
+    for window in (0..bits.div_ceil(CT_WINDOW)).rev() {
+        for _ in 0..CT_WINDOW {
+            res = ring.mul(res, res);
+        }
+
+        let mut digit = 0;
+        for j in 0..CT_WINDOW {
+            let bit = window * CT_WINDOW + j;
+            if bit < bits {
+                digit |= usize::from(exp.bit(bit)) << j;
+            }
+        }
+
+        let mut power = powers[0];
+        for (j, candidate) in powers.iter().enumerate() {
+            power.conditional_assign(candidate, j.ct_eq(&digit));
+        }
+        res = ring.mul(res, power);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use big_num::{types::U1024, BigUint};
+
+    use crate::{tests::xorshift, ModularArithmetic, MontgomeryRing, Ring};
+
+    // Plain square and multiply, to check the windowed methods against
+    fn binary<R: ModularArithmetic<u64>>(ring: &R, base: u64, exp: u64) -> u64 {
+        let mut res = ring.to_repr(1);
+
+        for i in (0..64).rev() {
+            res = ring.mul(res, res);
+            if (exp >> i) & 1 == 1 {
+                res = ring.mul(res, base);
+            }
+        }
+
+        res
+    }
+
+    #[test]
+    fn matches_square_and_multiply() {
+        let ring = Ring::new(1_000_000_007u64);
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+
+        for shift in [0, 1, 7, 20, 30, 40, 50, 63] {
+            let random = xorshift(&mut seed);
+            let base = random % 1_000_000_007;
+            let exp = random >> shift;
+
+            let expected = binary(&ring, base, exp);
+            assert_eq!(expected, ring.pow(base, exp), "{}^{}", base, exp);
+            assert_eq!(expected, ring.pow_ct(base, exp), "{}^{}", base, exp);
+        }
+    }
+
+    #[test]
+    fn known_values() {
+        let ring = Ring::new(1_000_000_007u64);
+
+        assert_eq!(976371285, ring.pow(2, 100));
+        assert_eq!(225959915, ring.pow(3, (1 << 40) + 12345));
+        assert_eq!(225959915, ring.pow_ct(3, (1 << 40) + 12345));
+        assert_eq!(1, ring.pow(5, 0));
+        assert_eq!(1, ring.pow_ct(5, 0));
+        assert_eq!(0, ring.pow(0, 9));
+        assert_eq!(0, ring.pow_ct(0, 9));
+    }
+
+    #[test]
+    fn large_windows() {
+        let modulus = U1024::from_be_hex_const(
+            "b969ec07f1f83a79af371d87d8a8f065a3f96f0e51436d1fcd68615c80690847\
+             dc159e6a409c38f26b68b48ebf13c171d0b0090d625909923fb81d2706e55426\
+             eae0d2c11c339464473d212ba950666d8a4996efb447c0ceb48438b5c41f9dfd\
+             2cb85f3f4a24e39a5d998017f5e2fc574dad2986ce8349606a06e9ab85a0bcc1",
+        );
+        let base = U1024::from_be_hex_const(
+            "dbf09529af81dda9da14f5079168e06b0c4f27b35c11b5aecda386a3a0b730d8\
+             8fe1e8a4aa1f9db8dd8a3b09dd54bec7d835c33744af929a91f4873115cd425e\
+             c38f1389998869510db4a02517e1ff83ab26a2658f32521553e014be00caa7e9\
+             bfd00724a123cf493f0febddf88d1a6bffff9a39142335e9e266cea9fa",
+        );
+        // 700 and 1024 bit exponents, which use windows of 6 bits
+        let cases = [
+            (
+                "8ce02d25ac9cd0ebd27b4f2c0855ac9e599e3580d3a2dc6925b1ec95f4807bc6\
+                 ecace3c0490ca26561d2417eb837ba8818dced3d0398c72689edcd6cfec9e2ca\
+                 ebf999324405d969995d8aaff0f3b81a3e2889c795e846b",
+                "24e1f00b1bec92a9d6f322eea9c7c593f98722600aae32d4ea82e73e6ffc8594\
+                 a970ab6fc903d5eb6482d003dfab94e3585c9e836a05caad73917845c7760db3\
+                 cd63b50f04992541216188ca55d64224bb5a10e7b1df22420c54f0a9e2a244ef\
+                 4f11b7faed7bbbcf479d2536c842a7a1a4e8f1bf82a356afe3ddf8002cb7384a",
+            ),
+            (
+                "c00b49322125fd8ae6d747b48db0eb42887b5f5f8e384a7bd422051f9a98f4d0\
+                 bf5da6cc3157e672468629e8c95613ef1071e6da44673e230ee06e0b5c15cfd1\
+                 f515f75186e415243fa244adf517a77536be6e688e8b88c21df995313d2b9a36\
+                 67cc1752de27660b01520627c63d6f69947feaa35ff4cb507a2c3b72f5a2224a",
+                "9fd8e63dbc5f3caadec4fc147b508bb98ea2a7883f04dcf12baab2e57f91091a\
+                 67c95e43b1638248807569f2f6b40ee6fb114e691c6880146ca24a0853fd9561\
+                 5aefc923b452fd8c164bef38f088b1480579e5969cf551dfd36d74c41bba6beb\
+                 9cd197b44037bf21e14f04f94e698f13ebdc23e3b164c589f067875c12467f35",
+            ),
+        ];
+
+        let ring = MontgomeryRing::new(modulus);
+        let base = ring.to_repr(base);
+
+        for (exp, expected) in cases {
+            let exp = BigUint::from_be_hex_const(exp);
+            let expected = BigUint::from_be_hex_const(expected);
+
+            assert_eq!(expected, ring.to_residue(ring.pow(base, exp)));
+            assert_eq!(expected, ring.to_residue(ring.pow_ct(base, exp)));
+        }
+    }
+}
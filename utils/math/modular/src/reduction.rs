@@ -0,0 +1,384 @@
+use big_num::{
+    types::{U1280, U256, U384, U512, U640, U768},
+    BigUint, DoubleWord, Word,
+};
+use numeric::{Narrow, RemEuclid, Widen};
+
+use crate::{Narrowed, Widened};
+
+// Reduces double width values, such as the product of two elements, modulo a fixed modulus. `Ring`
+// uses one for every addition and multiplication.
+pub trait Reducer<T: Narrowed>: Sized
+where
+    <T as Widen>::Output: Widened<T>,
+{
+    fn new(modulus: T) -> Self;
+    fn reduce(&self, a: <T as Widen>::Output) -> T;
+}
+
+// Long division by the modulus, which works for any modulus and any numeric type
+pub struct DivisionReducer<T> {
+    modulus: T,
+}
+
+impl<T: Narrowed> Reducer<T> for DivisionReducer<T>
+where
+    <T as Widen>::Output: Widened<T>,
+{
+    fn new(modulus: T) -> Self {
+        DivisionReducer { modulus }
+    }
+
+    fn reduce(&self, a: <T as Widen>::Output) -> T {
+        a.rem_euclid(&self.modulus.widen()).narrow()
+    }
+}
+
+// Barrett reduction replaces the division by a multiplication with mu = floor(b^2k / m), where b is
+// the limb radix and k the number of limbs of m. Values of up to 2k limbs are reduced with two
+// multiplications and at most two subtractions.
+pub struct BarrettReducer<T: Widen> {
+    modulus: T,
+    mu: <T as Widen>::Output,
+    limbs: usize,
+}
+
+impl<const N: usize, const M: usize> Reducer<BigUint<N>> for BarrettReducer<BigUint<N>>
+where
+    BigUint<N>: Narrowed + Widen<Output = BigUint<M>>,
+    BigUint<M>: Widened<BigUint<N>>,
+{
+    fn new(modulus: BigUint<N>) -> Self {
+        let limbs = significant_limbs(&modulus.to_limbs());
+        assert!(limbs > 0, "modulus must not be zero");
+
+        // b^2k - 1 instead of b^2k, which does not fit when k = N, only changes mu if m is a power
+        // of two and is covered by the final subtractions in that case
+        let mut numerator = [0; M];
+        numerator[..2 * limbs].fill(Word::MAX);
+        let mu = BigUint::new(numerator) / modulus.widen();
+
+        BarrettReducer { modulus, mu, limbs }
+    }
+
+    fn reduce(&self, a: BigUint<M>) -> BigUint<N> {
+        let k = self.limbs;
+        let x = a.to_limbs();
+
+        if significant_limbs(&x) > 2 * k {
+            return a.rem_euclid(&self.modulus.widen()).narrow();
+        }
+
+        let m = self.modulus.to_limbs();
+        let mu = self.mu.to_limbs();
+
+        // q = floor(floor(x / b^(k - 1)) * mu / b^(k + 1)) is at most 2 below floor(x / m)
+        let mut q = [0; M];
+        mul_columns(&x[k - 1..2 * k], &mu[..=k], k + 1, &mut q[..=k]);
+
+        // r = x - q * m, computed modulo b^(k + 1)
+        let mut qm = [0; M];
+        mul_columns(&q[..=k], &m[..k], 0, &mut qm[..=k]);
+
+        let mut r = [0; M];
+        let mut borrow = false;
+        for i in 0..=k {
+            let (difference, b1) = x[i].overflowing_sub(qm[i]);
+            let (difference, b2) = difference.overflowing_sub(Word::from(borrow));
+            r[i] = difference;
+            borrow = b1 || b2;
+        }
+
+        let mut r = BigUint::new(r);
+        let m = self.modulus.widen();
+        while r >= m {
+            r = r - m;
+        }
+
+        r.narrow()
+    }
+}
+
+fn significant_limbs(limbs: &[Word]) -> usize {
+    limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |i| i + 1)
+}
+
+// Writes the product columns from `from` onwards into `out`, carrying every lower column through.
+// A column can outgrow a double word, so the overflow is counted in an extra limb.
+fn mul_columns(a: &[Word], b: &[Word], from: usize, out: &mut [Word]) {
+    let mut acc: DoubleWord = 0;
+    let mut acc_hi: Word = 0;
+
+    for col in 0..from + out.len() {
+        for i in col.saturating_sub(b.len() - 1)..=col.min(a.len() - 1) {
+            let (sum, overflow) =
+                acc.overflowing_add(DoubleWord::from(a[i]) * DoubleWord::from(b[col - i]));
+            acc = sum;
+            acc_hi += Word::from(overflow);
+        }
+
+        if col >= from {
+            out[col - from] = acc as Word;
+        }
+        acc = (acc >> Word::BITS) | (DoubleWord::from(acc_hi) << Word::BITS);
+        acc_hi = 0;
+    }
+}
+
+// The special-form reductions below work on the 32-bit words of FIPS 186-4, whatever the limb
+// width is.
+
+// Folds the signed word sums of a special-form reduction into a value in [0, p). `words` holds the
+// sum for every result word and may carry far beyond the width of p.
+fn fold<const N: usize>(words: [i64; N], p: &[u32; N]) -> [u32; N] {
+    let mut w = [0; N];
+    let mut carry: i64 = 0;
+
+    for i in 0..N {
+        let sum = words[i] + carry;
+        w[i] = sum as u32;
+        carry = sum >> 32;
+    }
+
+    // carry counts whole multiples of 2^(32N) and is small, so a few additions or subtractions of p
+    // bring the value into range
+    while carry < 0 {
+        let mut k = 0;
+        for i in 0..N {
+            let sum = u64::from(w[i]) + u64::from(p[i]) + k;
+            w[i] = sum as u32;
+            k = sum >> 32;
+        }
+        carry += k as i64;
+    }
+
+    loop {
+        let mut difference = [0; N];
+        let mut borrow = 0;
+        for i in 0..N {
+            let (d, b1) = w[i].overflowing_sub(p[i]);
+            let (d, b2) = d.overflowing_sub(borrow);
+            difference[i] = d;
+            borrow = u32::from(b1 || b2);
+        }
+
+        if carry == 0 && borrow == 1 {
+            break;
+        }
+
+        w = difference;
+        carry -= i64::from(borrow);
+    }
+
+    w
+}
+
+// Sums the terms of a special-form reduction. Every term lists, from the least significant word,
+// which input word ends up in each result word, with an index past the input standing for zero.
+fn sum_terms<const N: usize, const M: usize>(
+    c: &[u32; M],
+    terms: &[(i64, [usize; N])],
+) -> [i64; N] {
+    let mut words = [0; N];
+
+    for (coefficient, indices) in terms {
+        for (word, &index) in words.iter_mut().zip(indices) {
+            if index < M {
+                *word += coefficient * i64::from(c[index]);
+            }
+        }
+    }
+
+    words
+}
+
+const P256: [u32; 8] = [0xffffffff, 0xffffffff, 0xffffffff, 0, 0, 0, 1, 0xffffffff];
+
+// p = 2^256 - 2^224 + 2^192 + 2^96 - 1, reduced as in FIPS 186-4 D.2.3
+pub struct P256Reducer;
+
+impl Reducer<U256> for P256Reducer {
+    fn new(modulus: U256) -> Self {
+        assert!(
+            modulus == BigUint::from_words(P256),
+            "modulus must be the P-256 prime"
+        );
+        P256Reducer
+    }
+
+    fn reduce(&self, a: U512) -> U256 {
+        const Z: usize = 16;
+        const TERMS: [(i64, [usize; 8]); 9] = [
+            (1, [0, 1, 2, 3, 4, 5, 6, 7]),
+            (2, [Z, Z, Z, 11, 12, 13, 14, 15]),
+            (2, [Z, Z, Z, 12, 13, 14, 15, Z]),
+            (1, [8, 9, 10, Z, Z, Z, 14, 15]),
+            (1, [9, 10, 11, 13, 14, 15, 13, 8]),
+            (-1, [11, 12, 13, Z, Z, Z, 8, 10]),
+            (-1, [12, 13, 14, 15, Z, Z, 9, 11]),
+            (-1, [13, 14, 15, 8, 9, 10, Z, 12]),
+            (-1, [14, 15, Z, 9, 10, 11, Z, 13]),
+        ];
+
+        BigUint::from_words(fold(sum_terms(&a.to_words::<16>(), &TERMS), &P256))
+    }
+}
+
+const P384: [u32; 12] = [
+    0xffffffff, 0, 0, 0xffffffff, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+    0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+// p = 2^384 - 2^128 - 2^96 + 2^32 - 1, reduced as in FIPS 186-4 D.2.4
+pub struct P384Reducer;
+
+impl Reducer<U384> for P384Reducer {
+    fn new(modulus: U384) -> Self {
+        assert!(
+            modulus == BigUint::from_words(P384),
+            "modulus must be the P-384 prime"
+        );
+        P384Reducer
+    }
+
+    fn reduce(&self, a: U768) -> U384 {
+        const Z: usize = 24;
+        const TERMS: [(i64, [usize; 12]); 10] = [
+            (1, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+            (2, [Z, Z, Z, Z, 21, 22, 23, Z, Z, Z, Z, Z]),
+            (1, [12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23]),
+            (1, [21, 22, 23, 12, 13, 14, 15, 16, 17, 18, 19, 20]),
+            (1, [Z, 23, Z, 20, 12, 13, 14, 15, 16, 17, 18, 19]),
+            (1, [Z, Z, Z, Z, 20, 21, 22, 23, Z, Z, Z, Z]),
+            (1, [20, Z, Z, 21, 22, 23, Z, Z, Z, Z, Z, Z]),
+            (-1, [23, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22]),
+            (-1, [Z, 20, 21, 22, 23, Z, Z, Z, Z, Z, Z, Z]),
+            (-1, [Z, Z, Z, 23, 23, Z, Z, Z, Z, Z, Z, Z]),
+        ];
+
+        BigUint::from_words(fold(sum_terms(&a.to_words::<24>(), &TERMS), &P384))
+    }
+}
+
+// p = 2^521 - 1, so x = hi * 2^521 + lo ≡ hi + lo
+pub struct P521Reducer;
+
+const P521: [u32; 20] = [
+    0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+    0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+    0x1ff, 0, 0, 0,
+];
+
+impl Reducer<U640> for P521Reducer {
+    fn new(modulus: U640) -> Self {
+        assert!(
+            modulus == BigUint::from_words(P521),
+            "modulus must be the P-521 prime"
+        );
+        P521Reducer
+    }
+
+    fn reduce(&self, a: U1280) -> U640 {
+        let mut x: [u32; 40] = a.to_words();
+
+        // every fold roughly halves the length, so products of reduced values need one or two
+        loop {
+            let mut hi = [0; 40];
+            for (i, word) in hi.iter_mut().enumerate().take(40 - 16) {
+                *word = (x[16 + i] >> 9) | x.get(17 + i).map_or(0, |next| next << 23);
+            }
+
+            if hi == [0; 40] {
+                break;
+            }
+
+            x[16] &= 0x1ff;
+            x[17..].fill(0);
+            x = (U1280::from_words(x) + U1280::from_words(hi)).to_words();
+        }
+
+        // x < 2^521 now, so it can only be p itself if it is not reduced yet
+        let x: U640 = U1280::from_words(x).narrow();
+        if x == BigUint::from_words(P521) {
+            BigUint::default()
+        } else {
+            x
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use big_num::types::U192;
+    use numeric::{One, Zero};
+
+    use super::*;
+    use crate::tests::xorshift;
+
+    fn random_limbs<const M: usize>(state: &mut u64) -> [Word; M] {
+        let mut limbs = [0; M];
+        for limb in limbs.iter_mut() {
+            *limb = xorshift(state) as Word;
+        }
+        limbs
+    }
+
+    fn check_reducer<const N: usize, const M: usize, R: Reducer<BigUint<N>>>(modulus: BigUint<N>)
+    where
+        BigUint<N>: Narrowed + Widen<Output = BigUint<M>>,
+        BigUint<M>: Widened<BigUint<N>>,
+    {
+        let reducer = R::new(modulus);
+        let division = DivisionReducer::new(modulus);
+
+        let p_minus_one = (modulus - BigUint::one()).widen();
+        let edge_cases = [
+            BigUint::zero(),
+            BigUint::one(),
+            modulus.widen(),
+            p_minus_one,
+            p_minus_one * p_minus_one,
+            BigUint::new([Word::MAX; M]),
+        ];
+
+        for x in edge_cases {
+            assert_eq!(division.reduce(x), reducer.reduce(x), "{:?}", x);
+        }
+
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let x = BigUint::new(random_limbs::<M>(&mut state));
+            assert_eq!(division.reduce(x), reducer.reduce(x), "{:?}", x);
+        }
+    }
+
+    #[test]
+    fn barrett_matches_division() {
+        check_reducer::<_, _, BarrettReducer<U256>>(BigUint::from_words(P256));
+        check_reducer::<_, _, BarrettReducer<U384>>(BigUint::from_words(P384));
+        check_reducer::<_, _, BarrettReducer<U640>>(BigUint::from_words(P521));
+
+        // moduli that do not use every limb, and a power of two
+        check_reducer::<_, _, BarrettReducer<U192>>(U192::from(97u8));
+        check_reducer::<_, _, BarrettReducer<U192>>(BigUint::from_words([
+            0x89abcdef, 0x01234567, 0xdeadbeef, 0, 0, 0,
+        ]));
+        check_reducer::<_, _, BarrettReducer<U192>>(BigUint::from_words([0, 0, 1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn nist_reducers_match_division() {
+        check_reducer::<_, _, P256Reducer>(BigUint::from_words(P256));
+        check_reducer::<_, _, P384Reducer>(BigUint::from_words(P384));
+        check_reducer::<_, _, P521Reducer>(BigUint::from_words(P521));
+    }
+
+    #[test]
+    #[should_panic]
+    fn nist_reducer_rejects_other_moduli() {
+        P256Reducer::new(U256::from(97u8));
+    }
+}
@@ -0,0 +1,121 @@
+use big_num::{BigUint, Word};
+use numeric::{One, SetBit};
+
+use crate::{is_prime, primality::SMALL_PRIMES, random_bits, rem_limb, RandomSource};
+
+fn check_bits<const N: usize>(bits: usize, min: usize) {
+    assert!(
+        (min..=N * Word::BITS as usize).contains(&bits),
+        "bit length must be in the range {}..={}",
+        min,
+        N * Word::BITS as usize
+    );
+}
+
+// Random odd number of exactly the given bit length
+fn random_odd<const N: usize, R>(bits: usize, rng: &mut R) -> BigUint<N>
+where
+    R: RandomSource + ?Sized,
+{
+    let mut candidate: BigUint<N> = random_bits(bits, rng);
+    candidate.set_bit(bits - 1, true);
+    candidate.set_bit(0, true);
+    candidate
+}
+
+// Random odd prime of exactly the given bit length, checked with Baillie-PSW. Every candidate is
+// drawn afresh rather than searched for incrementally, which would favour primes after long gaps.
+pub fn random_prime<const N: usize, R>(bits: usize, rng: &mut R) -> BigUint<N>
+where
+    R: RandomSource + ?Sized,
+{
+    check_bits::<N>(bits, 2);
+
+    loop {
+        let candidate = random_odd(bits, rng);
+        if is_prime(&candidate) {
+            return candidate;
+        }
+    }
+}
+
+// Random prime p = 2q + 1 of exactly the given bit length, where q is prime too
+pub fn random_safe_prime<const N: usize, R>(bits: usize, rng: &mut R) -> BigUint<N>
+where
+    R: RandomSource + ?Sized,
+{
+    check_bits::<N>(bits, 3);
+
+    loop {
+        let q: BigUint<N> = random_odd(bits - 1, rng);
+        let p = (q << 1) + BigUint::one();
+
+        // p is divisible by a small prime r when q = (r - 1) / 2 mod r, which rules out most
+        // candidates before either of them is tested. Too short candidates could be r itself.
+        let sieved = bits > 10
+            && SMALL_PRIMES
+                .iter()
+                .skip(1)
+                .any(|&r| rem_limb(&q, r) == 0 || rem_limb(&q, r) == r / 2);
+
+        if !sieved && is_prime(&q) && is_prime(&p) {
+            return p;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::xorshift;
+    use crate::{baillie_psw, miller_rabin_random};
+    use big_num::types::U256;
+    use numeric::BitLength;
+
+    #[test]
+    fn random_primes_have_the_requested_length() {
+        let mut rng = xorshift(0x2545_f491_4f6c_dd1d);
+
+        for bits in [2, 3, 8, 17, 64, 100, 256] {
+            let p: U256 = random_prime(bits, &mut rng);
+
+            assert_eq!(bits, p.bit_length(), "{:x}", p);
+            assert!(baillie_psw(&p), "{:x}", p);
+            assert!(miller_rabin_random(&p, 10, &mut rng), "{:x}", p);
+        }
+    }
+
+    #[test]
+    fn random_safe_primes() {
+        let mut rng = xorshift(0x9e37_79b9_7f4a_7c15);
+
+        for bits in [3, 5, 12, 64, 128] {
+            let p: U256 = random_safe_prime(bits, &mut rng);
+            let q = p >> 1;
+
+            assert_eq!(bits, p.bit_length(), "{:x}", p);
+            assert!(is_prime(&p), "{:x}", p);
+            assert!(is_prime(&q), "{:x}", q);
+        }
+    }
+
+    #[test]
+    fn generation_is_deterministic_for_a_seed() {
+        let p: U256 = random_prime(128, &mut xorshift(42));
+        let q: U256 = random_prime(128, &mut xorshift(42));
+
+        assert_eq!(p, q);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit length must be in the range 2..=")]
+    fn rejects_too_short_primes() {
+        let _: U256 = random_prime(1, &mut xorshift(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "bit length must be in the range 3..=")]
+    fn rejects_too_long_safe_primes() {
+        let _: U256 = random_safe_prime(257, &mut xorshift(1));
+    }
+}
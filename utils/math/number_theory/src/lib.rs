@@ -0,0 +1,76 @@
+#![no_std]
+
+extern crate alloc;
+
+mod generation;
+mod primality;
+
+pub use generation::{random_prime, random_safe_prime};
+pub use primality::{baillie_psw, is_prime, miller_rabin, miller_rabin_random, strong_lucas};
+
+use alloc::vec;
+use big_num::{BigUint, DoubleWord, Word};
+use numeric::{BitLength, FromBeSlice};
+
+// Source of the random bytes behind prime generation and random Miller-Rabin bases. Closures
+// filling a buffer implement it, so any generator can be plugged in without a wrapper.
+pub trait RandomSource {
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+impl<F: FnMut(&mut [u8])> RandomSource for F {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self(dest)
+    }
+}
+
+// Uniform in [0, 2^bits)
+pub(crate) fn random_bits<const N: usize, R>(bits: usize, rng: &mut R) -> BigUint<N>
+where
+    R: RandomSource + ?Sized,
+{
+    let len = bits.div_ceil(8);
+    let mut bytes = vec![0; len];
+    rng.fill_bytes(&mut bytes);
+    if let Some(first) = bytes.first_mut() {
+        *first &= 0xff >> (8 * len - bits);
+    }
+
+    BigUint::from_be_slice(&bytes).expect("bits fit in the integer")
+}
+
+// Uniform in [0, bound), by rejecting draws of the bound's bit length that are too large
+pub(crate) fn random_below<const N: usize, R>(bound: &BigUint<N>, rng: &mut R) -> BigUint<N>
+where
+    R: RandomSource + ?Sized,
+{
+    loop {
+        let candidate = random_bits(bound.bit_length(), rng);
+        if candidate < *bound {
+            return candidate;
+        }
+    }
+}
+
+// n mod m for a single limb m, without the long division of BigUint
+pub(crate) fn rem_limb<const N: usize>(n: &BigUint<N>, m: Word) -> Word {
+    n.to_limbs().iter().rev().fold(0, |rem, &limb| {
+        let value = (DoubleWord::from(rem) << Word::BITS) | DoubleWord::from(limb);
+        (value % DoubleWord::from(m)) as Word
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    // xorshift, a deterministic byte source so the tests need no extra dependencies
+    pub(crate) fn xorshift(mut seed: u64) -> impl FnMut(&mut [u8]) {
+        move |dest: &mut [u8]| {
+            for byte in dest {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                *byte = seed as u8;
+            }
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use big_num::{BigUint, Word};
+use modular::{ModularArithmetic, MontgomeryRing};
+use numeric::{Bit, BitLength, CheckedAdd, One, TrailingZeros, Zero};
+
+use crate::{random_below, rem_limb, RandomSource};
+
+pub(crate) const SMALL_PRIMES: [Word; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+// 257^2, the smallest composite without a factor in SMALL_PRIMES
+const TRIAL_DIVISION_LIMIT: u32 = 257 * 257;
+
+// Selfridge's search for D stops to rule out perfect squares after this many candidates, as no
+// D with (D/n) = -1 exists for them
+const SQUARE_CHECK_AFTER: usize = 5;
+
+fn small<const N: usize>(value: u32) -> BigUint<N> {
+    BigUint::from_words([value])
+}
+
+// Settles n when it is below 2, has a small prime factor or is too small to have a large one.
+// None means n is odd, above TRIAL_DIVISION_LIMIT and still needs a probable prime test.
+fn trial_division<const N: usize>(n: &BigUint<N>) -> Option<bool> {
+    if *n < small(2) {
+        return Some(false);
+    }
+
+    for p in SMALL_PRIMES {
+        if rem_limb(n, p) == 0 {
+            return Some(*n == small(p as u32));
+        }
+    }
+
+    if *n < small(TRIAL_DIVISION_LIMIT) {
+        return Some(true);
+    }
+
+    None
+}
+
+// n - 1 = d * 2^s with d odd, and the representations of 1 and -1 to compare against
+struct MillerRabin<'a, const N: usize> {
+    ring: &'a MontgomeryRing<N>,
+    d: BigUint<N>,
+    s: usize,
+    one: BigUint<N>,
+    minus_one: BigUint<N>,
+}
+
+impl<'a, const N: usize> MillerRabin<'a, N> {
+    fn new(ring: &'a MontgomeryRing<N>) -> Self {
+        let n_minus_one = ring.modulus() - BigUint::one();
+        let s = n_minus_one.trailing_zeros() as usize;
+
+        MillerRabin {
+            ring,
+            d: n_minus_one >> s,
+            s,
+            one: ring.to_repr(BigUint::one()),
+            minus_one: ring.to_repr(n_minus_one),
+        }
+    }
+
+    // n is a strong probable prime to base a when a^d = 1 or a^(d * 2^r) = -1 for some r < s
+    fn test(&self, base: BigUint<N>) -> bool {
+        let mut x = self.ring.pow(self.ring.to_repr(base), self.d);
+        if x == self.one || x == self.minus_one {
+            return true;
+        }
+
+        for _ in 1..self.s {
+            x = self.ring.mul(x, x);
+            if x == self.minus_one {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+// Strong probable prime test to a single base. Bases that are 0 or +-1 modulo n say nothing about
+// it and pass.
+pub fn miller_rabin<const N: usize>(n: &BigUint<N>, base: &BigUint<N>) -> bool {
+    if *n < small(4) {
+        return *n >= small(2);
+    }
+    if !n.bit(0) {
+        return false;
+    }
+
+    // 0^d is 0 rather than +-1, so a zero base is let through here instead of failing the test
+    let base = *base % *n;
+    if base == BigUint::zero() {
+        return true;
+    }
+
+    let ring = MontgomeryRing::new(*n);
+    MillerRabin::new(&ring).test(base)
+}
+
+// Miller-Rabin with uniformly random bases in [2, n - 2]. A composite passes a round with
+// probability at most 1/4.
+pub fn miller_rabin_random<const N: usize, R>(n: &BigUint<N>, rounds: usize, rng: &mut R) -> bool
+where
+    R: RandomSource + ?Sized,
+{
+    if let Some(result) = trial_division(n) {
+        return result;
+    }
+
+    let ring = MontgomeryRing::new(*n);
+    let test = MillerRabin::new(&ring);
+    let bound = *n - small(3);
+
+    (0..rounds).all(|_| test.test(random_below(&bound, rng) + small(2)))
+}
+
+// Jacobi symbol (a/n) for odd n
+fn jacobi<const N: usize>(a: BigUint<N>, n: BigUint<N>) -> i8 {
+    let (mut a, mut n) = (a % n, n);
+    let mut result = 1;
+
+    while a != BigUint::zero() {
+        let zeros = a.trailing_zeros();
+        a = a >> zeros as usize;
+
+        // (2/n) = -1 exactly when n = 3 or 5 mod 8
+        let n_mod_8 = n.to_limbs()[0] & 7;
+        if zeros % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            result = -result;
+        }
+
+        // quadratic reciprocity flips the sign when both are 3 mod 4
+        if a.to_limbs()[0] & 3 == 3 && n_mod_8 & 3 == 3 {
+            result = -result;
+        }
+
+        core::mem::swap(&mut a, &mut n);
+        a = a % n;
+    }
+
+    if n == BigUint::one() {
+        result
+    } else {
+        0
+    }
+}
+
+fn is_square<const N: usize>(n: &BigUint<N>) -> bool {
+    // Newton's iteration for the integer square root, starting above it
+    let mut x = BigUint::one() << n.bit_length().div_ceil(2);
+    loop {
+        let y = (x + *n / x) >> 1;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+
+    x * x == *n
+}
+
+fn signed_repr<const N: usize>(ring: &MontgomeryRing<N>, value: i64) -> BigUint<N> {
+    let magnitude = ring.to_repr(small(value.unsigned_abs() as u32) % ring.modulus());
+
+    if value < 0 {
+        ring.sub(BigUint::zero(), magnitude)
+    } else {
+        magnitude
+    }
+}
+
+// x / 2 modulo the odd modulus n
+fn half<const N: usize>(x: BigUint<N>, n: &BigUint<N>) -> BigUint<N> {
+    if x.bit(0) {
+        (x >> 1) + (*n >> 1) + BigUint::one()
+    } else {
+        x >> 1
+    }
+}
+
+// Strong Lucas probable prime test with Selfridge's parameters: D is the first of 5, -7, 9, -11, ...
+// with (D/n) = -1, P = 1 and Q = (1 - D) / 4.
+pub fn strong_lucas<const N: usize>(n: &BigUint<N>) -> bool {
+    if *n < small(3) {
+        return *n == small(2);
+    }
+    if !n.bit(0) {
+        return false;
+    }
+
+    let mut d: i64 = 5;
+    for attempt in 0.. {
+        let magnitude = small(d.unsigned_abs() as u32);
+        let residue = if d < 0 {
+            (*n - magnitude % *n) % *n
+        } else {
+            magnitude % *n
+        };
+
+        match jacobi(residue, *n) {
+            -1 => break,
+            // D shares a factor with n, so n can only be prime if it is |D| itself
+            0 => return *n == magnitude && !is_square(n),
+            _ => {}
+        }
+
+        if attempt + 1 == SQUARE_CHECK_AFTER && is_square(n) {
+            return false;
+        }
+
+        d = if d < 0 { -d + 2 } else { -d - 2 };
+    }
+
+    let ring = MontgomeryRing::new(*n);
+    let d_repr = signed_repr(&ring, d);
+    let q = signed_repr(&ring, (1 - d) / 4);
+    let zero = BigUint::zero();
+
+    // n + 1 = k * 2^s with k odd. Only all ones overflows, which is divisible by 3.
+    let Some(n_plus_one) = n.checked_add(&BigUint::one()) else {
+        return false;
+    };
+    let s = n_plus_one.trailing_zeros() as usize;
+    let k = n_plus_one >> s;
+
+    // U_1 = 1, V_1 = P = 1, walking the bits of k down from the top
+    let one = ring.to_repr(BigUint::one());
+    let (mut u, mut v, mut q_k) = (one, one, q);
+
+    for i in (0..k.bit_length() - 1).rev() {
+        // U_2j = U_j V_j, V_2j = V_j^2 - 2 Q^j
+        u = ring.mul(u, v);
+        v = ring.sub(ring.mul(v, v), ring.add(q_k, q_k));
+        q_k = ring.mul(q_k, q_k);
+
+        if k.bit(i) {
+            // U_j+1 = (P U_j + V_j) / 2, V_j+1 = (D U_j + P V_j) / 2
+            (u, v) = (
+                half(ring.add(u, v), n),
+                half(ring.add(ring.mul(d_repr, u), v), n),
+            );
+            q_k = ring.mul(q_k, q);
+        }
+    }
+
+    if u == zero || v == zero {
+        return true;
+    }
+
+    for _ in 1..s {
+        v = ring.sub(ring.mul(v, v), ring.add(q_k, q_k));
+        if v == zero {
+            return true;
+        }
+        q_k = ring.mul(q_k, q_k);
+    }
+
+    false
+}
+
+// Baillie-PSW: a strong probable prime test to base 2 followed by a strong Lucas test. No
+// composite passing both is known, and there is none below 2^64.
+pub fn baillie_psw<const N: usize>(n: &BigUint<N>) -> bool {
+    if let Some(result) = trial_division(n) {
+        return result;
+    }
+
+    let ring = MontgomeryRing::new(*n);
+    MillerRabin::new(&ring).test(small(2)) && strong_lucas(n)
+}
+
+pub fn is_prime<const N: usize>(n: &BigUint<N>) -> bool {
+    baillie_psw(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::xorshift;
+    use big_num::types::U256;
+
+    fn int(value: u64) -> BigUint<4> {
+        BigUint::from_words([value as u32, (value >> 32) as u32])
+    }
+
+    const SIEVE_LIMIT: usize = 5000;
+
+    fn sieve() -> [bool; SIEVE_LIMIT] {
+        let mut prime = [true; SIEVE_LIMIT];
+        prime[0] = false;
+        prime[1] = false;
+
+        let mut i = 2;
+        while i * i < SIEVE_LIMIT {
+            if prime[i] {
+                for multiple in (i * i..SIEVE_LIMIT).step_by(i) {
+                    prime[multiple] = false;
+                }
+            }
+            i += 1;
+        }
+
+        prime
+    }
+
+    #[test]
+    fn matches_sieve() {
+        let prime = sieve();
+
+        for (n, &expected) in prime.iter().enumerate() {
+            assert_eq!(expected, is_prime(&int(n as u64)), "{}", n);
+            assert_eq!(expected, strong_lucas(&int(n as u64)), "{}", n);
+        }
+    }
+
+    #[test]
+    fn strong_pseudoprimes_to_base_2() {
+        // each passes Miller-Rabin to base 2, and the last ones to several more small bases
+        for n in [
+            2047,
+            3277,
+            4033,
+            4681,
+            8321,
+            3215031751,
+            2152302898747,
+            3474749660383,
+        ] {
+            assert!(miller_rabin(&int(n), &int(2)), "{}", n);
+            assert!(!is_prime(&int(n)), "{}", n);
+        }
+
+        assert!(miller_rabin(&int(3215031751), &int(7)));
+        assert!(!miller_rabin(&int(3215031751), &int(11)));
+    }
+
+    #[test]
+    fn bases_divisible_by_n() {
+        assert!(miller_rabin(&int(7), &int(7)));
+        assert!(miller_rabin(&int(7), &int(14)));
+        assert!(miller_rabin(&int(1_000_000_007), &int(0)));
+    }
+
+    #[test]
+    fn strong_lucas_pseudoprimes() {
+        // the smallest strong Lucas pseudoprimes, which Miller-Rabin to base 2 catches
+        for n in [5459, 5777, 10877, 16109, 18971] {
+            assert!(strong_lucas(&int(n)), "{}", n);
+            assert!(!miller_rabin(&int(n), &int(2)), "{}", n);
+            assert!(!is_prime(&int(n)), "{}", n);
+        }
+    }
+
+    #[test]
+    fn carmichael_numbers() {
+        for n in [
+            561, 1105, 1729, 2465, 2821, 6601, 8911, 41041, 825265, 321197185,
+        ] {
+            assert!(!is_prime(&int(n)), "{}", n);
+        }
+    }
+
+    #[test]
+    fn squares() {
+        for p in [257u64, 65537, 4294967291] {
+            assert!(!is_prime(&(int(p) * int(p))), "{}^2", p);
+        }
+    }
+
+    #[test]
+    fn large_primes() {
+        let primes = [
+            // 2^127 - 1 and the P-256 field and group orders
+            U256::from_be_hex_const("7fffffff ffffffff ffffffff ffffffff"),
+            U256::from_be_hex_const(
+                "ffffffff 00000001 00000000 00000000 00000000 ffffffff ffffffff ffffffff",
+            ),
+            U256::from_be_hex_const(
+                "ffffffff 00000000 ffffffff ffffffff bce6faad a7179e84 f3b9cac2 fc632551",
+            ),
+        ];
+
+        for p in primes {
+            assert!(is_prime(&p), "{:x}", p);
+            assert!(!is_prime(&(p * small(3))), "3 * {:x}", p);
+        }
+
+        // 2^128 + 1 = 59649589127497217 * 5704689200685129054721
+        let fermat = (U256::one() << 128) + BigUint::one();
+        assert!(!is_prime(&fermat));
+    }
+
+    #[test]
+    fn random_bases() {
+        let mut rng = xorshift(0x2545_f491_4f6c_dd1d);
+
+        assert!(miller_rabin_random(&int(4294967291), 20, &mut rng));
+        assert!(!miller_rabin_random(&int(3215031751), 20, &mut rng));
+        assert!(!miller_rabin_random(&int(561), 20, &mut rng));
+        assert!(miller_rabin_random(&int(2), 20, &mut rng));
+    }
+
+    #[test]
+    fn jacobi_symbol() {
+        // (a/15) for a = 0..15
+        let expected = [0, 1, 1, 0, 1, 0, 0, -1, 1, 0, 0, -1, 0, -1, -1];
+        for (a, &symbol) in expected.iter().enumerate() {
+            assert_eq!(symbol, jacobi(int(a as u64), int(15)), "({}/15)", a);
+        }
+    }
+}
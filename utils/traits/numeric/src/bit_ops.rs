@@ -52,3 +52,58 @@ macro_rules! impl_set_bit {
 }
 
 impl_set_bit!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait TrailingZeros {
+    fn trailing_zeros(&self) -> u32;
+}
+
+macro_rules! impl_trailing_zeros {
+    ($($t:ty)*) => {
+        $(
+            impl TrailingZeros for $t {
+                fn trailing_zeros(&self) -> u32 {
+                    <$t>::trailing_zeros(*self)
+                }
+            }
+        )*
+    }
+}
+
+impl_trailing_zeros!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait CountOnes {
+    fn count_ones(&self) -> u32;
+}
+
+macro_rules! impl_count_ones {
+    ($($t:ty)*) => {
+        $(
+            impl CountOnes for $t {
+                fn count_ones(&self) -> u32 {
+                    <$t>::count_ones(*self)
+                }
+            }
+        )*
+    }
+}
+
+impl_count_ones!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+// Number of bits needed to write the value, i.e. the index of the highest set bit plus one
+pub trait BitLength {
+    fn bit_length(&self) -> usize;
+}
+
+macro_rules! impl_bit_length {
+    ($($t:ty)*) => {
+        $(
+            impl BitLength for $t {
+                fn bit_length(&self) -> usize {
+                    (<$t>::BITS - <$t>::leading_zeros(*self)) as usize
+                }
+            }
+        )*
+    }
+}
+
+impl_bit_length!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
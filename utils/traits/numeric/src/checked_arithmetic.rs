@@ -57,3 +57,67 @@ macro_rules! impl_checked_add {
 }
 
 impl_checked_add!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait CheckedDiv {
+    fn checked_div(&self, rhs: &Self) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_checked_div {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedDiv for $t {
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_div(*self, *rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_checked_div!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait CheckedRem {
+    fn checked_rem(&self, rhs: &Self) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_checked_rem {
+    ($($t:ty)*) => {
+        $(
+            impl CheckedRem for $t {
+                fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_rem(*self, *rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_checked_rem!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_div_rem_by_zero() {
+        assert_eq!(None, CheckedDiv::checked_div(&7u32, &0));
+        assert_eq!(None, CheckedRem::checked_rem(&7u32, &0));
+        assert_eq!(None, CheckedDiv::checked_div(&-7i64, &0));
+        assert_eq!(None, CheckedRem::checked_rem(&-7i64, &0));
+        assert_eq!(Some(3), CheckedDiv::checked_div(&7usize, &2));
+        assert_eq!(Some(-1), CheckedRem::checked_rem(&-7i8, &2));
+    }
+
+    #[test]
+    fn checked_div_rem_overflow() {
+        // MIN / -1 is one past MAX
+        assert_eq!(None, CheckedDiv::checked_div(&i32::MIN, &-1));
+        assert_eq!(None, CheckedRem::checked_rem(&i32::MIN, &-1));
+        assert_eq!(Some(i128::MAX), CheckedDiv::checked_div(&-i128::MAX, &-1));
+        assert_eq!(Some(0), CheckedRem::checked_rem(&u128::MAX, &u128::MAX));
+    }
+}
@@ -0,0 +1,240 @@
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+// A secret boolean, stored as 0 or 1. Values go through `black_box` when they are created, so the
+// optimiser cannot see that a selection over a `Choice` only has two outcomes and turn it back
+// into a branch.
+#[derive(Copy, Clone, Debug)]
+pub struct Choice(u8);
+
+impl Choice {
+    pub fn unwrap_u8(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for Choice {
+    fn from(value: u8) -> Self {
+        debug_assert!(value <= 1, "choice must be 0 or 1");
+        Choice(core::hint::black_box(value))
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(value: bool) -> Self {
+        Choice::from(value as u8)
+    }
+}
+
+// Leaves constant time, for results that are public, like the outcome of a signature check
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> Self {
+        choice.0 != 0
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Choice::from(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Choice::from(self.0 | rhs.0)
+    }
+}
+
+impl BitXor for Choice {
+    type Output = Choice;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Choice::from(self.0 ^ rhs.0)
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Self::Output {
+        Choice::from(1 ^ self.0)
+    }
+}
+
+pub trait ConstantTimeEq {
+    fn ct_eq(&self, other: &Self) -> Choice;
+
+    fn ct_ne(&self, other: &Self) -> Choice {
+        !self.ct_eq(other)
+    }
+}
+
+pub trait ConstantTimeLess {
+    fn ct_lt(&self, other: &Self) -> Choice;
+
+    fn ct_gt(&self, other: &Self) -> Choice {
+        other.ct_lt(self)
+    }
+}
+
+pub trait ConditionallySelectable: Copy {
+    // Returns `b` if `choice` is set and `a` otherwise, without branching on `choice`
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let t = *a;
+        a.conditional_assign(b, choice);
+        b.conditional_assign(&t, choice);
+    }
+}
+
+// Signed types are paired with the unsigned type of the same width, which the comparisons are
+// done in
+macro_rules! impl_constant_time {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl ConstantTimeEq for $t {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    // the top bit of x | -x is set exactly when x is non-zero
+                    let x = (*self ^ *other) as $u;
+                    let nonzero = (x | x.wrapping_neg()) >> (<$u>::BITS - 1);
+                    Choice::from(1 ^ nonzero as u8)
+                }
+            }
+
+            impl ConditionallySelectable for $t {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    let mask = (choice.unwrap_u8() as $t).wrapping_neg();
+                    *a ^ (mask & (*a ^ *b))
+                }
+            }
+        )*
+    }
+}
+
+impl_constant_time!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize);
+impl_constant_time!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! impl_ct_less_unsigned {
+    ($($t:ty)*) => {
+        $(
+            impl ConstantTimeLess for $t {
+                fn ct_lt(&self, other: &Self) -> Choice {
+                    // Hacker's Delight 2-12: the top bit is the borrow out of x - y
+                    let (x, y) = (*self, *other);
+                    let lt = ((!x & y) | ((!x | y) & x.wrapping_sub(y))) >> (<$t>::BITS - 1);
+                    Choice::from(lt as u8)
+                }
+            }
+        )*
+    }
+}
+
+impl_ct_less_unsigned!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_ct_less_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl ConstantTimeLess for $t {
+                fn ct_lt(&self, other: &Self) -> Choice {
+                    // flipping the sign bit maps the signed order onto the unsigned one
+                    let flip = 1 << (<$u>::BITS - 1);
+                    ((*self as $u) ^ flip).ct_lt(&((*other as $u) ^ flip))
+                }
+            }
+        )*
+    }
+}
+
+impl_ct_less_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+// Slices of different lengths are unequal; only their contents are compared in constant time
+impl<T: ConstantTimeEq> ConstantTimeEq for [T] {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.len() != other.len() {
+            return Choice::from(0);
+        }
+
+        self.iter()
+            .zip(other)
+            .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
+    }
+}
+
+impl ConstantTimeEq for Choice {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        !(*self ^ *other)
+    }
+}
+
+impl ConditionallySelectable for Choice {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Choice::from(u8::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exhaustive_u8() {
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                assert_eq!(a == b, a.ct_eq(&b).into(), "{} == {}", a, b);
+                assert_eq!(a < b, a.ct_lt(&b).into(), "{} < {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn exhaustive_i8() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                assert_eq!(a == b, a.ct_eq(&b).into(), "{} == {}", a, b);
+                assert_eq!(a < b, a.ct_lt(&b).into(), "{} < {}", a, b);
+            }
+        }
+    }
+
+    // Pairs around zero and both ends of the range, where a borrow or a sign flip goes wrong first
+    macro_rules! check_boundaries {
+        ($($t:ty),*) => {
+            $(
+                let values: [$t; 7] = [
+                    <$t>::MIN,
+                    <$t>::MIN + 1,
+                    (0 as $t).saturating_sub(1),
+                    0,
+                    1,
+                    <$t>::MAX - 1,
+                    <$t>::MAX,
+                ];
+
+                for a in values {
+                    for b in values {
+                        assert_eq!(a == b, a.ct_eq(&b).into(), "{} == {}", a, b);
+                        assert_eq!(a < b, a.ct_lt(&b).into(), "{} < {}", a, b);
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn boundaries_unsigned() {
+        check_boundaries!(u16, u32, u64, u128, usize);
+    }
+
+    #[test]
+    fn boundaries_signed() {
+        check_boundaries!(i16, i32, i64, i128, isize);
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use core::num::ParseIntError;
 
 pub trait Widen {
@@ -66,7 +67,7 @@ pub trait FromStrRadix {
 }
 
 macro_rules! impl_from_str_radix {
-    ($($t:ty => $n:ty),*) => {
+    ($($t:ty)*) => {
         $(
             impl FromStrRadix for $t {
                 type Error = ParseIntError;
@@ -78,17 +79,67 @@ macro_rules! impl_from_str_radix {
     };
 }
 
-impl_from_str_radix! {
-    i16 => i8,
-    u16 => u8,
-    i32 => i16,
-    u32 => u16,
-    i64 => i32,
-    u64 => u32,
-    i128 => i64,
-    u128 => u64
+impl_from_str_radix!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+// Lowercase digits with no prefix, and a leading '-' for negative values, so the output parses
+// back with `FromStrRadix`. Panics if the radix is not in 2..=36.
+pub trait ToStrRadix {
+    fn to_str_radix(&self, radix: u32) -> String;
+}
+
+fn magnitude_to_str_radix(mut magnitude: u128, negative: bool, radix: u32) -> String {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range 2..=36"
+    );
+
+    let mut digits = String::new();
+
+    loop {
+        let digit = (magnitude % u128::from(radix)) as u32;
+        digits.push(char::from_digit(digit, radix).expect("remainder is below the radix"));
+        magnitude /= u128::from(radix);
+
+        if magnitude == 0 {
+            break;
+        }
+    }
+
+    if negative {
+        digits.push('-');
+    }
+
+    digits.chars().rev().collect()
 }
 
+macro_rules! impl_to_str_radix_unsigned {
+    ($($t:ty)*) => {
+        $(
+            impl ToStrRadix for $t {
+                fn to_str_radix(&self, radix: u32) -> String {
+                    magnitude_to_str_radix(*self as u128, false, radix)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_str_radix_unsigned!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_to_str_radix_signed {
+    ($($t:ty)*) => {
+        $(
+            impl ToStrRadix for $t {
+                fn to_str_radix(&self, radix: u32) -> String {
+                    magnitude_to_str_radix(self.unsigned_abs() as u128, *self < 0, radix)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_str_radix_signed!(i8 i16 i32 i64 i128 isize);
+
 pub trait FromBeBytes {
     type Bytes;
 
@@ -109,14 +160,18 @@ macro_rules! impl_from_be_bytes {
 }
 
 impl_from_be_bytes! {
+    u8 => [u8; 1],
     u16 => [u8; 2],
     u32 => [u8; 4],
     u64 => [u8; 8],
     u128 => [u8; 16],
+    usize => [u8; core::mem::size_of::<usize>()],
+    i8 => [u8; 1],
     i16 => [u8; 2],
     i32 => [u8; 4],
     i64 => [u8; 8],
-    i128 => [u8; 16]
+    i128 => [u8; 16],
+    isize => [u8; core::mem::size_of::<isize>()]
 }
 
 pub trait ToBeBytes {
@@ -139,12 +194,344 @@ macro_rules! impl_to_be_bytes {
 }
 
 impl_to_be_bytes! {
+    u8 => [u8; 1],
     u16 => [u8; 2],
     u32 => [u8; 4],
     u64 => [u8; 8],
     u128 => [u8; 16],
+    usize => [u8; core::mem::size_of::<usize>()],
+    i8 => [u8; 1],
     i16 => [u8; 2],
     i32 => [u8; 4],
     i64 => [u8; 8],
-    i128 => [u8; 16]
+    i128 => [u8; 16],
+    isize => [u8; core::mem::size_of::<isize>()]
+}
+
+pub trait FromLeBytes {
+    type Bytes;
+
+    fn from_le_bytes(bytes: &Self::Bytes) -> Self;
+}
+
+macro_rules! impl_from_le_bytes {
+    ($($t:ty => $bytes:ty),*) => {
+        $(
+            impl FromLeBytes for $t {
+                type Bytes = $bytes;
+                fn from_le_bytes(bytes: &Self::Bytes) -> Self {
+                    Self::from_le_bytes(*bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_le_bytes! {
+    u8 => [u8; 1],
+    u16 => [u8; 2],
+    u32 => [u8; 4],
+    u64 => [u8; 8],
+    u128 => [u8; 16],
+    usize => [u8; core::mem::size_of::<usize>()],
+    i8 => [u8; 1],
+    i16 => [u8; 2],
+    i32 => [u8; 4],
+    i64 => [u8; 8],
+    i128 => [u8; 16],
+    isize => [u8; core::mem::size_of::<isize>()]
+}
+
+pub trait ToLeBytes {
+    type Bytes: AsRef<[u8]>;
+
+    fn to_le_bytes(&self) -> Self::Bytes;
+}
+
+macro_rules! impl_to_le_bytes {
+    ($($t:ty => $bytes:ty),*) => {
+        $(
+            impl ToLeBytes for $t {
+                type Bytes = $bytes;
+                fn to_le_bytes(&self) -> Self::Bytes {
+                    Self::to_le_bytes(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_le_bytes! {
+    u8 => [u8; 1],
+    u16 => [u8; 2],
+    u32 => [u8; 4],
+    u64 => [u8; 8],
+    u128 => [u8; 16],
+    usize => [u8; core::mem::size_of::<usize>()],
+    i8 => [u8; 1],
+    i16 => [u8; 2],
+    i32 => [u8; 4],
+    i64 => [u8; 8],
+    i128 => [u8; 16],
+    isize => [u8; core::mem::size_of::<isize>()]
+}
+
+// Big endian conversions from and to byte strings of any length. Leading zero bytes are ignored
+// when reading, and added when writing. Signed types use two's complement, so for them the leading
+// bytes repeat the sign instead.
+pub trait FromBeSlice: Sized {
+    // None if the value does not fit in Self
+    fn from_be_slice(bytes: &[u8]) -> Option<Self>;
+}
+
+pub trait ToBeSlice {
+    // Fills all of out, None if the value needs more bytes than that
+    fn to_be_slice(&self, out: &mut [u8]) -> Option<()>;
+}
+
+macro_rules! impl_be_slice {
+    ($($t:ty),*) => {
+        $(
+            impl FromBeSlice for $t {
+                fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+                    let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(bytes.len());
+                    let bytes = &bytes[start..];
+
+                    let mut padded = [0; core::mem::size_of::<$t>()];
+                    let offset = padded.len().checked_sub(bytes.len())?;
+                    padded[offset..].copy_from_slice(bytes);
+
+                    Some(<$t>::from_be_bytes(padded))
+                }
+            }
+
+            impl ToBeSlice for $t {
+                fn to_be_slice(&self, out: &mut [u8]) -> Option<()> {
+                    let bytes = <$t>::to_be_bytes(*self);
+                    let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(bytes.len());
+                    let bytes = &bytes[start..];
+
+                    let offset = out.len().checked_sub(bytes.len())?;
+                    out[..offset].fill(0);
+                    out[offset..].copy_from_slice(bytes);
+
+                    Some(())
+                }
+            }
+        )*
+    };
+}
+
+impl_be_slice!(u8, u16, u32, u64, u128, usize);
+
+// 0xff for a byte with the sign bit set, 0 otherwise
+fn sign_extension(byte: u8) -> u8 {
+    ((byte as i8) >> 7) as u8
+}
+
+macro_rules! impl_be_slice_signed {
+    ($($t:ty),*) => {
+        $(
+            impl FromBeSlice for $t {
+                fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+                    let fill = sign_extension(bytes.first().copied().unwrap_or(0));
+                    let mut padded = [fill; core::mem::size_of::<$t>()];
+
+                    match bytes.len().checked_sub(padded.len()) {
+                        None => {
+                            let offset = padded.len() - bytes.len();
+                            padded[offset..].copy_from_slice(bytes);
+                        }
+                        Some(offset) => {
+                            // the dropped bytes may only repeat the sign of the kept ones
+                            let (extension, bytes) = bytes.split_at(offset);
+                            let sign = bytes.first().map_or(0, |&byte| sign_extension(byte));
+                            if sign != fill || extension.iter().any(|&byte| byte != fill) {
+                                return None;
+                            }
+                            padded.copy_from_slice(bytes);
+                        }
+                    }
+
+                    Some(<$t>::from_be_bytes(padded))
+                }
+            }
+
+            impl ToBeSlice for $t {
+                fn to_be_slice(&self, out: &mut [u8]) -> Option<()> {
+                    let bytes = <$t>::to_be_bytes(*self);
+                    let fill = sign_extension(bytes[0]);
+
+                    match out.len().checked_sub(bytes.len()) {
+                        Some(offset) => {
+                            out[..offset].fill(fill);
+                            out[offset..].copy_from_slice(&bytes);
+                        }
+                        None => {
+                            let (extension, bytes) = bytes.split_at(bytes.len() - out.len());
+                            let sign = bytes.first().map_or(0, |&byte| sign_extension(byte));
+                            if sign != fill || extension.iter().any(|&byte| byte != fill) {
+                                return None;
+                            }
+                            out.copy_from_slice(bytes);
+                        }
+                    }
+
+                    Some(())
+                }
+            }
+        )*
+    };
+}
+
+impl_be_slice_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn str_radix_round_trip() {
+        macro_rules! check {
+            ($($t:ty),*) => {
+                $(
+                    for value in [<$t>::MIN, <$t>::MIN + 1, 0, 1, 35, 36, <$t>::MAX - 1, <$t>::MAX] {
+                        for radix in 2..=36 {
+                            let digits = value.to_str_radix(radix);
+                            assert_eq!(
+                                Ok(value),
+                                <$t as FromStrRadix>::from_str_radix(&digits, radix),
+                                "{} in base {}",
+                                value,
+                                radix
+                            );
+                        }
+
+                        assert_eq!(format!("{}", value), value.to_str_radix(10));
+                    }
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn str_radix_digits() {
+        assert_eq!("ff", u8::MAX.to_str_radix(16));
+        assert_eq!("-80", i8::MIN.to_str_radix(16));
+        assert_eq!("-1", (-1i32).to_str_radix(2));
+        assert_eq!("z", 35u16.to_str_radix(36));
+        assert_eq!(format!("{:x}", usize::MAX), usize::MAX.to_str_radix(16));
+        assert_eq!(format!("{:o}", u128::MAX), u128::MAX.to_str_radix(8));
+        assert_eq!(format!("-1{}", "0".repeat(127)), i128::MIN.to_str_radix(2));
+        assert_eq!(
+            format!("-8{}", "0".repeat(2 * core::mem::size_of::<isize>() - 1)),
+            isize::MIN.to_str_radix(16)
+        );
+        assert_eq!(Ok(255), <u8 as FromStrRadix>::from_str_radix("ff", 16));
+        assert_eq!(Ok(-128), <isize as FromStrRadix>::from_str_radix("-80", 16));
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2..=36")]
+    fn to_str_radix_invalid_radix() {
+        1u32.to_str_radix(1);
+    }
+
+    #[test]
+    fn le_bytes_round_trip() {
+        macro_rules! check {
+            ($($t:ty),*) => {
+                $(
+                    for value in [<$t>::MIN, <$t>::MIN + 1, 0, 1, 0x5a, <$t>::MAX - 1, <$t>::MAX] {
+                        let le = ToLeBytes::to_le_bytes(&value);
+                        let mut be = ToBeBytes::to_be_bytes(&value);
+                        be.reverse();
+
+                        assert_eq!(be, le, "{}", value);
+                        assert_eq!(value, <$t as FromLeBytes>::from_le_bytes(&le), "{}", value);
+                        assert_eq!(value, <$t as FromBeBytes>::from_be_bytes(&ToBeBytes::to_be_bytes(&value)));
+                    }
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn le_bytes_order() {
+        assert_eq!([0x34, 0x12], ToLeBytes::to_le_bytes(&0x1234u16));
+        assert_eq!([0x12, 0x34], ToBeBytes::to_be_bytes(&0x1234u16));
+        assert_eq!([0xfe, 0xff, 0xff, 0xff], ToLeBytes::to_le_bytes(&-2i32));
+        assert_eq!(
+            -2,
+            <i32 as FromLeBytes>::from_le_bytes(&[0xfe, 0xff, 0xff, 0xff])
+        );
+        assert_eq!([0x80], ToLeBytes::to_le_bytes(&i8::MIN));
+    }
+
+    #[test]
+    fn be_slice_unsigned() {
+        let mut out = [0xaa; 6];
+        assert_eq!(Some(()), 0x1234u32.to_be_slice(&mut out));
+        assert_eq!([0, 0, 0, 0, 0x12, 0x34], out);
+        assert_eq!(Some(0x1234), u32::from_be_slice(&out));
+
+        assert_eq!(None, 0x1234u16.to_be_slice(&mut [0; 1]));
+        assert_eq!(Some(0x34), u8::from_be_slice(&[0, 0, 0x34]));
+        assert_eq!(None, u8::from_be_slice(&[1, 0x34]));
+        assert_eq!(Some(0), usize::from_be_slice(&[]));
+        assert_eq!(
+            Some(usize::MAX),
+            usize::from_be_slice(&usize::MAX.to_be_bytes())
+        );
+    }
+
+    #[test]
+    fn be_slice_signed_extends_the_sign() {
+        let mut out = [0; 8];
+        assert_eq!(Some(()), (-1i32).to_be_slice(&mut out));
+        assert_eq!([0xff; 8], out);
+        assert_eq!(Some(-1), i32::from_be_slice(&out));
+
+        assert_eq!(Some(()), (-2i16).to_be_slice(&mut out[..1]));
+        assert_eq!(0xfe, out[0]);
+        assert_eq!(Some(-2), i64::from_be_slice(&out[..1]));
+        assert_eq!(Some(0x7f), i128::from_be_slice(&[0, 0, 0x7f]));
+        assert_eq!(Some(0), isize::from_be_slice(&[]));
+    }
+
+    #[test]
+    fn be_slice_signed_out_of_range() {
+        // 128 and -129 need a second byte for the sign
+        assert_eq!(None, 128i16.to_be_slice(&mut [0; 1]));
+        assert_eq!(None, (-129i16).to_be_slice(&mut [0; 1]));
+        assert_eq!(None, (-1i8).to_be_slice(&mut []));
+        assert_eq!(None, i8::from_be_slice(&[0, 0x80]));
+        assert_eq!(None, i8::from_be_slice(&[0xff, 0x7f]));
+        assert_eq!(None, i16::from_be_slice(&[1, 0, 0]));
+        assert_eq!(Some(i8::MIN), i8::from_be_slice(&[0xff, 0xff, 0x80]));
+    }
+
+    #[test]
+    fn be_slice_round_trip() {
+        macro_rules! check {
+            ($($t:ty),*) => {
+                $(
+                    for value in [<$t>::MIN, <$t>::MIN + 1, 0, 1, <$t>::MAX - 1, <$t>::MAX] {
+                        for len in core::mem::size_of::<$t>()..core::mem::size_of::<$t>() + 3 {
+                            let mut out = [0; 19];
+                            assert_eq!(Some(()), value.to_be_slice(&mut out[..len]));
+                            assert_eq!(Some(value), <$t>::from_be_slice(&out[..len]), "{}", value);
+                        }
+                    }
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    }
 }
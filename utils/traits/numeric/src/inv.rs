@@ -0,0 +1,5 @@
+// Multiplicative inverse, for types where it only exists for some values, like the elements of a
+// finite field other than zero
+pub trait Inv: Sized {
+    fn inv(&self) -> Option<Self>;
+}
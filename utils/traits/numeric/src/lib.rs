@@ -1,15 +1,25 @@
 #![no_std]
 
+extern crate alloc;
+
 pub mod bit_ops;
 pub mod checked_arithmetic;
+pub mod constant_time;
 pub mod conversions;
 pub mod euclid;
 pub mod identities;
+pub mod inv;
+pub mod overflowing_arithmetic;
+pub mod pow;
 pub mod wrapping_arithmetic;
 
 pub use bit_ops::*;
 pub use checked_arithmetic::*;
+pub use constant_time::*;
 pub use conversions::*;
 pub use euclid::*;
 pub use identities::*;
+pub use inv::*;
+pub use overflowing_arithmetic::*;
+pub use pow::*;
 pub use wrapping_arithmetic::*;
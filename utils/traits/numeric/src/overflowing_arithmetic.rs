@@ -0,0 +1,165 @@
+pub trait OverflowingAdd {
+    fn overflowing_add(&self, rhs: &Self) -> (Self, bool)
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_overflowing_add {
+    ($($t:ty)*) => {
+        $(
+            impl OverflowingAdd for $t {
+                fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+                    <$t>::overflowing_add(*self, *rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_overflowing_add!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait OverflowingSub {
+    fn overflowing_sub(&self, rhs: &Self) -> (Self, bool)
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_overflowing_sub {
+    ($($t:ty)*) => {
+        $(
+            impl OverflowingSub for $t {
+                fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+                    <$t>::overflowing_sub(*self, *rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_overflowing_sub!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+// Full product as a (low, high) pair of the same type, so multiplications of limbs can be chained
+// without a wider type. Unsigned only, the halves of a signed product have different signedness.
+pub trait WideningMul {
+    fn widening_mul(&self, rhs: &Self) -> (Self, Self)
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_widening_mul {
+    ($($t:ty, $wide:ty)*) => {
+        $(
+            impl WideningMul for $t {
+                fn widening_mul(&self, rhs: &Self) -> (Self, Self) {
+                    let product = <$wide>::from(*self) * <$wide>::from(*rhs);
+                    (product as $t, (product >> <$t>::BITS) as $t)
+                }
+            }
+        )*
+    }
+}
+
+impl_widening_mul!(u8, u16 u16, u32 u32, u64 u64, u128);
+
+// No wider type is available, so the product is put together from 64-bit halves
+impl WideningMul for u128 {
+    fn widening_mul(&self, rhs: &Self) -> (Self, Self) {
+        let (a_low, a_high) = (*self as u64 as u128, *self >> 64);
+        let (b_low, b_high) = (*rhs as u64 as u128, *rhs >> 64);
+
+        let low = a_low * b_low;
+        let cross_1 = a_low * b_high;
+        let cross_2 = a_high * b_low;
+        let high = a_high * b_high;
+
+        // the middle column can carry into the high half by one more bit
+        let (middle, carry) = cross_1.overflowing_add(cross_2);
+        let (low, low_carry) = low.overflowing_add(middle << 64);
+        let high = high + (middle >> 64) + ((carry as u128) << 64) + low_carry as u128;
+
+        (low, high)
+    }
+}
+
+impl WideningMul for usize {
+    fn widening_mul(&self, rhs: &Self) -> (Self, Self) {
+        let product = (*self as u128) * (*rhs as u128);
+        (product as usize, (product >> usize::BITS) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widening_mul_u128() {
+        let mul = |a: u128, b: u128| WideningMul::widening_mul(&a, &b);
+
+        assert_eq!((1, u128::MAX - 1), mul(u128::MAX, u128::MAX));
+        assert_eq!((0, 0), mul(u128::MAX, 0));
+        assert_eq!((u128::MAX, 0), mul(u128::MAX, 1));
+        assert_eq!((0, 1), mul(1 << 64, 1 << 64));
+
+        // the two middle products overflow when added
+        assert_eq!(
+            (
+                0x235a_1df7_6f0d_5adf_fedc_ba98_7654_3211,
+                0xfedc_ba98_7654_3210_0245_3f68_3723_a530
+            ),
+            mul(
+                0xfedc_ba98_7654_3210_ffff_ffff_ffff_ffff,
+                0xffff_ffff_ffff_ffff_0123_4567_89ab_cdef
+            )
+        );
+
+        // the middle column added to the low product carries into the high half
+        assert_eq!(
+            (0xffff_ffff_ffff_fffd_0000_0000_0000_0001, 1),
+            mul(0x1_ffff_ffff_ffff_ffff, 0xffff_ffff_ffff_ffff)
+        );
+    }
+
+    #[test]
+    fn widening_mul_matches_the_wider_product() {
+        for (a, b) in [
+            (0, 0),
+            (1, u64::MAX),
+            (u64::MAX, u64::MAX),
+            (0xdead_beef, 1 << 63),
+        ] {
+            let product = a as u128 * b as u128;
+            let expected = (product as u64, (product >> 64) as u64);
+
+            assert_eq!(expected, WideningMul::widening_mul(&a, &b));
+            assert_eq!(
+                (expected.0 as usize, expected.1 as usize),
+                WideningMul::widening_mul(&(a as usize), &(b as usize))
+            );
+        }
+
+        assert_eq!((1, 0xfe), WideningMul::widening_mul(&u8::MAX, &u8::MAX));
+    }
+
+    #[test]
+    fn overflowing_edges() {
+        assert_eq!((0, true), OverflowingAdd::overflowing_add(&u64::MAX, &1));
+        assert_eq!(
+            (u64::MAX, false),
+            OverflowingAdd::overflowing_add(&u64::MAX, &0)
+        );
+        assert_eq!(
+            (i32::MIN, true),
+            OverflowingAdd::overflowing_add(&i32::MAX, &1)
+        );
+        assert_eq!((u8::MAX, true), OverflowingSub::overflowing_sub(&0u8, &1));
+        assert_eq!(
+            (i128::MAX, true),
+            OverflowingSub::overflowing_sub(&i128::MIN, &1)
+        );
+        assert_eq!(
+            (0, false),
+            OverflowingSub::overflowing_sub(&i128::MIN, &i128::MIN)
+        );
+    }
+}
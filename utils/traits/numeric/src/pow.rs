@@ -0,0 +1,40 @@
+// Panics on overflow, like `*`
+pub trait Pow {
+    fn pow(&self, exp: u32) -> Self;
+}
+
+macro_rules! impl_pow {
+    ($($t:ty)*) => {
+        $(
+            impl Pow for $t {
+                fn pow(&self, exp: u32) -> Self {
+                    <$t>::pow(*self, exp)
+                }
+            }
+        )*
+    }
+}
+
+impl_pow!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow_edges() {
+        assert_eq!(1, Pow::pow(&0u32, 0));
+        assert_eq!(0, Pow::pow(&0u32, 5));
+        assert_eq!(1 << 127, Pow::pow(&2u128, 127));
+        assert_eq!(i64::MIN, Pow::pow(&-2i64, 63));
+        assert_eq!(-27, Pow::pow(&-3i8, 3));
+    }
+
+    // like `*`, the primitives only check for overflow with debug assertions
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflow")]
+    fn pow_overflow() {
+        let _ = Pow::pow(&2u64, 64);
+    }
+}
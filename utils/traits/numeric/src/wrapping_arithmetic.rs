@@ -17,3 +17,58 @@ macro_rules! impl_wrapping_add {
 }
 
 impl_wrapping_add!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait WrappingSub {
+    fn wrapping_sub(&self, rhs: &Self) -> Self
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_wrapping_sub {
+    ($($t:ty)*) => {
+        $(
+            impl WrappingSub for $t {
+                fn wrapping_sub(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_sub(*self, *rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_wrapping_sub!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+pub trait WrappingMul {
+    fn wrapping_mul(&self, rhs: &Self) -> Self
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_wrapping_mul {
+    ($($t:ty)*) => {
+        $(
+            impl WrappingMul for $t {
+                fn wrapping_mul(&self, rhs: &Self) -> Self {
+                    <$t>::wrapping_mul(*self, *rhs)
+                }
+            }
+        )*
+    }
+}
+
+impl_wrapping_mul!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_edges() {
+        assert_eq!(0, WrappingAdd::wrapping_add(&u128::MAX, &1));
+        assert_eq!(i16::MIN, WrappingAdd::wrapping_add(&i16::MAX, &1));
+        assert_eq!(usize::MAX, WrappingSub::wrapping_sub(&0usize, &1));
+        assert_eq!(i64::MAX, WrappingSub::wrapping_sub(&i64::MIN, &1));
+        assert_eq!(1, WrappingMul::wrapping_mul(&u32::MAX, &u32::MAX));
+        assert_eq!(i8::MIN, WrappingMul::wrapping_mul(&i8::MIN, &-1));
+    }
+}